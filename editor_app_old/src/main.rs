@@ -2,7 +2,7 @@ use std::path::PathBuf;
 
 use app::EditorApp;
 
-use editor_core::{read_gvas, save_file::SaveFile};
+use editor_core::document::SaveDocument;
 use eframe::{egui, NativeOptions};
 
 mod app;
@@ -16,18 +16,16 @@ fn main() -> Result<(), eframe::Error> {
         ..Default::default()
     };
 
-    // let mut gvas = read_gvas(&PathBuf::from(
+    // let mut document = SaveDocument::load(&PathBuf::from(
     //     r"C:\Users\funlennysub\Documents\cockding\drg-save-editor\00000000000000000_Player.sav",
     // ))
     // .unwrap();
-    // let mut save = SaveFile::from_gvas(&gvas).unwrap();
-    // // save.miscellaneous.credits = i32::MAX;
-    // save.save(
-    //     &mut gvas,
-    //     &PathBuf::from(
+    // // document.save_mut().miscellaneous.credits = i32::MAX;
+    // document
+    //     .write_to(&PathBuf::from(
     //         r"C:\Program Files (x86)\Steam\steamapps\common\Deep Rock Galactic\FSD\Saved\SaveGames\76561198282694357_Player.sav",
-    //     ),
-    // );
+    //     ))
+    //     .unwrap();
 
     eframe::run_native(
         "DRG Save Editor",
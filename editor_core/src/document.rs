@@ -0,0 +1,162 @@
+use std::fmt;
+#[cfg(not(feature = "wasm"))]
+use std::path::{Path, PathBuf};
+
+use gvas::GvasFile;
+
+use crate::{
+    error::{Diagnostic, Error},
+    gvas_bytes, read_gvas_bytes,
+    save_file::{section::SaveSection, SaveFile},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveDocument {
+    gvas: GvasFile,
+    save: SaveFile,
+    source: Vec<u8>,
+    #[cfg(not(feature = "wasm"))]
+    path: Option<PathBuf>,
+    diagnostics: Vec<Diagnostic>,
+    // Whether the save was read with `SaveFile::from_gvas_lenient`, `revert`
+    // reads it the same way.
+    lenient: bool,
+    dirty: bool,
+}
+
+impl SaveDocument {
+    #[cfg(not(feature = "wasm"))]
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let bytes = std::fs::read(path)?;
+        let mut document = Self::from_bytes(bytes)?;
+        document.path = Some(path.to_owned());
+
+        Ok(document)
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    pub fn load_lenient(path: &Path) -> Result<Self, Error> {
+        let bytes = std::fs::read(path)?;
+        let mut document = Self::from_bytes_lenient(bytes)?;
+        document.path = Some(path.to_owned());
+
+        Ok(document)
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, Error> {
+        let gvas = read_gvas_bytes(&bytes)?;
+        let save = SaveFile::from_gvas(&gvas)?;
+
+        Ok(Self::new(gvas, save, bytes, None))
+    }
+
+    // Opens the save even if some sections fail to parse, those are listed in
+    // `diagnostics` and are written back unchanged.
+    pub fn from_bytes_lenient(bytes: Vec<u8>) -> Result<Self, Error> {
+        let gvas = read_gvas_bytes(&bytes)?;
+        let (save, diagnostics) = SaveFile::from_gvas_lenient(&gvas)?;
+
+        Ok(Self::new(gvas, save, bytes, Some(diagnostics)))
+    }
+
+    // `diagnostics` is `None` for documents loaded strictly.
+    fn new(
+        gvas: GvasFile,
+        save: SaveFile,
+        source: Vec<u8>,
+        diagnostics: Option<Vec<Diagnostic>>,
+    ) -> Self {
+        Self {
+            gvas,
+            save,
+            source,
+            #[cfg(not(feature = "wasm"))]
+            path: None,
+            lenient: diagnostics.is_some(),
+            diagnostics: diagnostics.unwrap_or_default(),
+            dirty: false,
+        }
+    }
+
+    pub fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    // See `SaveFile::register`, the section is read from the document's
+    // current GvasFile and read again on `revert`.
+    pub fn register<T>(&mut self) -> Result<(), Error>
+    where
+        T: SaveSection + Clone + PartialEq + fmt::Debug + 'static,
+    {
+        self.save.register::<T>(&self.gvas)
+    }
+
+    pub fn save(&self) -> &SaveFile {
+        &self.save
+    }
+
+    pub fn save_mut(&mut self) -> &mut SaveFile {
+        self.dirty = true;
+        &mut self.save
+    }
+
+    pub fn gvas(&self) -> &GvasFile {
+        &self.gvas
+    }
+
+    pub fn source(&self) -> &[u8] {
+        &self.source
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn apply(&mut self) -> Result<(), Error> {
+        self.save.save_loaded(&mut self.gvas, &self.diagnostics)
+    }
+
+    pub fn revert(&mut self) -> Result<(), Error> {
+        let gvas = read_gvas_bytes(&self.source)?;
+        let registered = self.save.registered.reread(&gvas)?;
+        (self.save, self.diagnostics) = match self.lenient {
+            true => SaveFile::from_gvas_lenient(&gvas)?,
+            false => (SaveFile::from_gvas(&gvas)?, Vec::new()),
+        };
+        self.save.registered = registered;
+        self.gvas = gvas;
+        self.dirty = false;
+
+        Ok(())
+    }
+
+    pub fn write(&mut self) -> Result<Vec<u8>, Error> {
+        self.apply()?;
+        let bytes = gvas_bytes(&self.gvas)?;
+        self.source = bytes.clone();
+        self.dirty = false;
+
+        Ok(bytes)
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    pub fn write_to(&mut self, path: &Path) -> Result<(), Error> {
+        self.apply()?;
+        let bytes = gvas_bytes(&self.gvas)?;
+        std::fs::write(path, &bytes)?;
+        self.source = bytes;
+        self.path = Some(path.to_owned());
+        self.dirty = false;
+
+        Ok(())
+    }
+}
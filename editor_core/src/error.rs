@@ -1,22 +1,92 @@
 use std::io;
 
+use gvas::{
+    properties::{struct_property::StructPropertyValue, Property},
+    types::Guid,
+};
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[cfg(feature = "field_access")]
+use crate::save_file::field_access::ValueType;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum ParsingError {
-    #[error("Entry {0} is missing")]
-    MissingEntry(String),
-    #[error("Failed to cast property to {0}")]
-    FailedCastError(String),
+    #[error("{}Entry {path} is missing", in_section(.section))]
+    MissingEntry {
+        path: String,
+        section: Option<String>,
+    },
+    #[error(
+        "{}Failed to cast {path} to {expected}, found {actual}",
+        in_section(.section)
+    )]
+    FailedCastError {
+        path: String,
+        expected: String,
+        actual: String,
+        section: Option<String>,
+    },
+    #[error("{}Invalid value at {path}: {reason}", in_section(.section))]
+    InvalidValue {
+        path: String,
+        reason: String,
+        section: Option<String>,
+    },
+}
+
+fn in_section(section: &Option<String>) -> String {
+    match section {
+        Some(section) => format!("{section}: "),
+        None => String::new(),
+    }
 }
 
 impl ParsingError {
-    pub fn missing_entry(entry: &str) -> Self {
-        Self::MissingEntry(entry.to_string())
+    pub fn missing_entry(path: &str) -> Self {
+        Self::MissingEntry {
+            path: path.to_string(),
+            section: None,
+        }
+    }
+
+    pub fn failed_cast(path: &str, expected: &str, actual: &str) -> Self {
+        Self::FailedCastError {
+            path: path.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+            section: None,
+        }
+    }
+
+    pub fn invalid_value(path: &str, reason: &str) -> Self {
+        Self::InvalidValue {
+            path: path.to_string(),
+            reason: reason.to_string(),
+            section: None,
+        }
     }
 
-    pub fn failed_cast(to: &str) -> Self {
-        Self::FailedCastError(to.to_string())
+    pub fn path(&self) -> &str {
+        match self {
+            Self::MissingEntry { path, .. }
+            | Self::FailedCastError { path, .. }
+            | Self::InvalidValue { path, .. } => path,
+        }
+    }
+
+    pub fn section(&self) -> Option<&str> {
+        match self {
+            Self::MissingEntry { section, .. }
+            | Self::FailedCastError { section, .. }
+            | Self::InvalidValue { section, .. } => section.as_deref(),
+        }
+    }
+
+    fn set_section(&mut self, name: &str) {
+        let (Self::MissingEntry { section, .. }
+        | Self::FailedCastError { section, .. }
+        | Self::InvalidValue { section, .. }) = self;
+        section.get_or_insert_with(|| name.to_string());
     }
 }
 
@@ -28,4 +98,121 @@ pub enum Error {
     Gvas(#[from] gvas::error::Error),
     #[error(transparent)]
     Io(#[from] io::Error),
+    #[error(transparent)]
+    Path(#[from] PathError),
+}
+
+impl Error {
+    pub(crate) fn in_section(mut self, section: &str) -> Self {
+        if let Self::Parsing(e) = &mut self {
+            e.set_section(section);
+        }
+
+        self
+    }
+}
+
+// Errors from `PropertyPath`, paths are the part that was reached.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    #[error("Invalid property path {0}")]
+    Syntax(String),
+    #[error("Property {0} doesn't exist")]
+    Missing(String),
+    #[error("Expected {expected} at {path}, found {found}")]
+    Mismatch {
+        path: String,
+        expected: &'static str,
+        found: String,
+    },
+}
+
+// Problems found while loading a save leniently, see `SaveFile::from_gvas_lenient`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    // The section couldn't be read, it's left at its default and won't be
+    // written back.
+    #[error("{0}")]
+    Skipped(ParsingError),
+    #[error("{section}: Unknown GUID {guid} at {path}")]
+    UnknownGuid {
+        section: String,
+        path: String,
+        guid: Guid,
+    },
+}
+
+impl Diagnostic {
+    pub fn section(&self) -> Option<&str> {
+        match self {
+            Self::Skipped(e) => e.section(),
+            Self::UnknownGuid { section, .. } => Some(section),
+        }
+    }
+}
+
+// Errors from the path based accessors, see `SaveFile::set_path`.
+#[cfg(feature = "field_access")]
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum FieldError {
+    #[error("Unknown field {0}")]
+    UnknownPath(String),
+    #[error("{path} expects {expected:?}, found {found:?}")]
+    TypeMismatch {
+        path: String,
+        expected: ValueType,
+        found: ValueType,
+    },
+    #[error("{value} is out of bounds for {path}, expected {min}..={max}")]
+    OutOfBounds {
+        path: String,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+}
+
+pub fn property_type(property: &Property) -> &'static str {
+    match property {
+        Property::ArrayProperty(_) => "ArrayProperty",
+        Property::BoolProperty(_) => "BoolProperty",
+        Property::ByteProperty(_) => "ByteProperty",
+        Property::DoubleProperty(_) => "DoubleProperty",
+        Property::EnumProperty(_) => "EnumProperty",
+        Property::FloatProperty(_) => "FloatProperty",
+        Property::Int16Property(_) => "Int16Property",
+        Property::Int64Property(_) => "Int64Property",
+        Property::Int8Property(_) => "Int8Property",
+        Property::IntProperty(_) => "IntProperty",
+        Property::MapProperty(_) => "MapProperty",
+        Property::NameProperty(_) => "NameProperty",
+        Property::ObjectProperty(_) => "ObjectProperty",
+        Property::DelegateProperty(_) => "DelegateProperty",
+        Property::MulticastInlineDelegateProperty(_) => "MulticastInlineDelegateProperty",
+        Property::MulticastSparseDelegateProperty(_) => "MulticastSparseDelegateProperty",
+        Property::FieldPathProperty(_) => "FieldPathProperty",
+        Property::SetProperty(_) => "SetProperty",
+        Property::StrProperty(_) => "StrProperty",
+        Property::StructProperty(_) => "StructProperty",
+        Property::TextProperty(_) => "TextProperty",
+        Property::UInt16Property(_) => "UInt16Property",
+        Property::UInt32Property(_) => "UInt32Property",
+        Property::UInt64Property(_) => "UInt64Property",
+        Property::UnknownProperty(_) => "UnknownProperty",
+    }
+}
+
+pub fn struct_type(value: &StructPropertyValue) -> &str {
+    match value {
+        StructPropertyValue::VectorF(_) => "VectorF",
+        StructPropertyValue::VectorD(_) => "VectorD",
+        StructPropertyValue::RotatorF(_) => "RotatorF",
+        StructPropertyValue::RotatorD(_) => "RotatorD",
+        StructPropertyValue::QuatF(_) => "QuatF",
+        StructPropertyValue::QuatD(_) => "QuatD",
+        StructPropertyValue::DateTime(_) => "DateTime",
+        StructPropertyValue::Guid(_) => "Guid",
+        StructPropertyValue::IntPoint(_) => "IntPoint",
+        StructPropertyValue::CustomStruct(name, _) => name,
+    }
 }
@@ -1,123 +1,309 @@
-use crate::error::{Error, ParsingError};
-use std::collections::HashMap;
+use crate::{
+    error::{struct_type, Error, ParsingError},
+    registry::get_hints,
+};
+use std::io::Cursor;
+#[cfg(not(feature = "wasm"))]
+use std::{fs::File, path::Path};
 
 use gvas::{
-    properties::{int_property::FloatProperty, Property},
+    properties::{
+        int_property::BoolProperty,
+        set_property::SetProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        Property,
+    },
     types::Guid,
     GvasFile,
 };
+use indexmap::IndexMap;
 
+pub mod document;
 pub mod error;
+pub mod property_path;
 pub mod registry;
+pub mod resources;
+pub mod roundtrip;
 pub mod save_file;
 
 pub fn gvas_json(gvas: &GvasFile) -> Result<String, serde_json::Error> {
     serde_json::to_string_pretty(gvas)
 }
 
-pub fn get_owned_resources(gvas: &GvasFile) -> Result<HashMap<&Guid, &FloatProperty>, Error> {
-    let props = &gvas.properties;
-    let cs_resources = get!(props, "Resources", StructProperty)?
-        .value
-        .get_custom_struct()
-        .ok_or_else(|| ParsingError::failed_cast("CustomStruct"))?;
-    let owned_resources = &cs_resources.1[0].1;
-    let owned_resources = &owned_resources
-        .get_map()
-        .ok_or_else(|| ParsingError::failed_cast("MapProperty"))?
-        .value;
-
-    let mut resources = HashMap::new();
-    for or in owned_resources {
-        let (k, v) = match or {
-            (Property::StructProperty(k), Property::FloatProperty(v)) => Ok((
-                k.value
-                    .get_guid()
-                    .ok_or_else(|| ParsingError::failed_cast("Guid"))?,
-                v,
-            )),
-            (Property::StructProperty(_), _) => Err(ParsingError::failed_cast("FloatProperty")),
-            _ => Err(ParsingError::failed_cast("StructProperty")),
-        }?;
-        resources.insert(k, v);
+#[cfg(not(feature = "wasm"))]
+pub fn read_gvas(path: &Path) -> Result<GvasFile, Error> {
+    let mut file = File::open(path)?;
+    Ok(GvasFile::read_with_hints(&mut file, &get_hints())?)
+}
+
+pub fn read_gvas_bytes(bytes: &[u8]) -> Result<GvasFile, Error> {
+    let mut cursor = Cursor::new(bytes);
+    Ok(GvasFile::read_with_hints(&mut cursor, &get_hints())?)
+}
+
+#[cfg(not(feature = "wasm"))]
+pub fn write_gvas(path: &Path, gvas: &GvasFile) -> Result<(), Error> {
+    let bytes = gvas_bytes(gvas)?;
+    std::fs::write(path, bytes)?;
+
+    Ok(())
+}
+
+pub fn gvas_bytes(gvas: &GvasFile) -> Result<Vec<u8>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    gvas.write(&mut cursor)?;
+
+    Ok(cursor.into_inner())
+}
+
+pub(crate) fn get_field<'a>(
+    fields: &'a [(String, Property)],
+    path: &str,
+    name: &str,
+) -> Result<&'a Property, Error> {
+    Ok(find_field(fields, name)
+        .ok_or_else(|| ParsingError::missing_entry(&format!("{path}.{name}")))?)
+}
+
+// Same as `get_field` for fields the game leaves out of some saves.
+pub(crate) fn find_field<'a>(fields: &'a [(String, Property)], name: &str) -> Option<&'a Property> {
+    fields.iter().find_map(|p| (p.0 == name).then_some(&p.1))
+}
+
+// Replaces, adds or with `None` removes the field `name`.
+pub(crate) fn set_field(fields: &mut Vec<(String, Property)>, name: &str, value: Option<Property>) {
+    let index = fields.iter().position(|p| p.0 == name);
+    match (index, value) {
+        (Some(i), Some(value)) => fields[i].1 = value,
+        (Some(i), None) => {
+            fields.remove(i);
+        }
+        (None, Some(value)) => fields.push((name.to_string(), value)),
+        (None, None) => (),
     }
+}
+
+pub(crate) fn get_field_mut<'a>(
+    fields: &'a mut [(String, Property)],
+    path: &str,
+    name: &str,
+) -> Result<&'a mut Property, Error> {
+    Ok(fields
+        .iter_mut()
+        .find_map(|p| match p.0 == name {
+            true => Some(&mut p.1),
+            false => None,
+        })
+        .ok_or_else(|| ParsingError::missing_entry(&format!("{path}.{name}")))?)
+}
+
+pub(crate) fn get_int(fields: &[(String, Property)], path: &str, name: &str) -> Result<i32, Error> {
+    let property = get_field(fields, path, name)?;
+    Ok(cast!(property, IntProperty, format!("{path}.{name}"))?.value)
+}
+
+pub(crate) fn set_int(
+    fields: &mut [(String, Property)],
+    path: &str,
+    name: &str,
+    value: i32,
+) -> Result<(), Error> {
+    let property = get_field_mut(fields, path, name)?;
+    cast!(property, IntProperty, format!("{path}.{name}"))?.value = value;
+
+    Ok(())
+}
+
+pub(crate) fn custom_struct<'a>(
+    property: &'a Property,
+    path: &str,
+) -> Result<&'a Vec<(String, Property)>, Error> {
+    match &cast!(property, StructProperty, path)?.value {
+        StructPropertyValue::CustomStruct(_, fields) => Ok(fields),
+        v => Err(ParsingError::failed_cast(path, "CustomStruct", struct_type(v)).into()),
+    }
+}
+
+pub(crate) fn custom_struct_mut<'a>(
+    property: &'a mut Property,
+    path: &str,
+) -> Result<&'a mut Vec<(String, Property)>, Error> {
+    match &mut cast!(property, StructProperty, path)?.value {
+        StructPropertyValue::CustomStruct(_, fields) => Ok(fields),
+        v => Err(ParsingError::failed_cast(path, "CustomStruct", struct_type(v)).into()),
+    }
+}
+
+pub(crate) fn get_guid(property: &Property, path: &str) -> Result<Guid, Error> {
+    match &cast!(property, StructProperty, path)?.value {
+        StructPropertyValue::Guid(guid) => Ok(*guid),
+        v => Err(ParsingError::failed_cast(path, "Guid", struct_type(v)).into()),
+    }
+}
+
+pub(crate) fn get_guids<'a>(
+    properties: impl IntoIterator<Item = &'a Property>,
+    path: &str,
+) -> Result<Vec<Guid>, Error> {
+    properties
+        .into_iter()
+        .enumerate()
+        .map(|(i, p)| get_guid(p, &format!("{path}[{i}]")))
+        .collect()
+}
+
+pub(crate) fn guid_property(guid: Guid) -> Property {
+    Property::from(StructProperty::new(
+        Guid::from(0),
+        StructPropertyValue::Guid(guid),
+    ))
+}
+
+// Looks up the elements of the SetProperty `name`, or `name.field` when the set
+// lives in a struct. Returns `None` when the save doesn't have the set.
+pub(crate) fn get_guid_set<'a>(
+    gvas: &'a GvasFile,
+    name: &str,
+    field: Option<&str>,
+) -> Result<Option<&'a Vec<Property>>, Error> {
+    let (property, path) = match (gvas.properties.get(name), field) {
+        (None, _) => return Ok(None),
+        (Some(p), None) => (p, name.to_string()),
+        (Some(p), Some(field)) => match find_field(custom_struct(p, name)?, field) {
+            Some(p) => (p, format!("{name}.{field}")),
+            None => return Ok(None),
+        },
+    };
 
-    Ok(resources)
+    Ok(Some(&cast!(property, SetProperty, path)?.properties))
 }
 
-pub fn get_owned_resources_mut(
+// Writes `guids` to the SetProperty `name`, or `name.field`. gvas 0.7 panics
+// when reading an empty set, so those are removed instead, a missing set reads
+// as empty. The struct holding `field` has to exist to add a set to it.
+pub(crate) fn write_guid_set(
     gvas: &mut GvasFile,
-) -> Result<HashMap<&Guid, &FloatProperty>, Error> {
+    name: &str,
+    field: Option<&str>,
+    guids: &[Guid],
+) -> Result<(), Error> {
     let props = &mut gvas.properties;
-    let cs_resources = get_mut!(props, "Resources", StructProperty)?
-        .value
-        .get_custom_struct()
-        .ok_or_else(|| ParsingError::failed_cast("CustomStruct"))?;
-    let owned_resources = &cs_resources.1[0].1;
-    let owned_resources = &owned_resources
-        .get_map()
-        .ok_or_else(|| ParsingError::failed_cast("MapProperty"))?
-        .value;
-
-    let mut resources = HashMap::new();
-    for or in owned_resources {
-        let (k, v) = match or {
-            (Property::StructProperty(k), Property::FloatProperty(v)) => Ok((
-                k.value
-                    .get_guid()
-                    .ok_or_else(|| ParsingError::failed_cast("Guid"))?,
-                v,
-            )),
-            (Property::StructProperty(_), _) => Err(ParsingError::failed_cast("FloatProperty")),
-            _ => Err(ParsingError::failed_cast("StructProperty")),
-        }?;
-        resources.insert(k, v);
+    let new_set = || {
+        Property::from(SetProperty::new(
+            "StructProperty".to_string(),
+            0,
+            Vec::new(),
+        ))
+    };
+
+    let (property, path) = match field {
+        None if guids.is_empty() => {
+            props.shift_remove(name);
+            return Ok(());
+        }
+        None => (
+            props.entry(name.to_string()).or_insert_with(new_set),
+            name.to_string(),
+        ),
+        Some(field) => {
+            let fields = match props.get_mut(name) {
+                Some(p) => custom_struct_mut(p, name)?,
+                None if guids.is_empty() => return Ok(()),
+                None => return Err(ParsingError::missing_entry(name).into()),
+            };
+            if guids.is_empty() {
+                set_field(fields, field, None);
+                return Ok(());
+            }
+            if find_field(fields, field).is_none() {
+                set_field(fields, field, Some(new_set()));
+            }
+
+            (
+                get_field_mut(fields, name, field)?,
+                format!("{name}.{field}"),
+            )
+        }
+    };
+
+    update_guid_set(&mut cast!(property, SetProperty, path)?.properties, guids);
+
+    Ok(())
+}
+
+// Rebuilds the Guid keys of `map` from `guids`, keeping the order of the
+// entries that stay and appending new ones with a value from `value`.
+pub(crate) fn write_guid_map(
+    map: &mut IndexMap<Property, Property>,
+    guids: &[Guid],
+    mut value: impl FnMut() -> Result<Property, Error>,
+) -> Result<(), Error> {
+    map.retain(|k, _| get_guid(k, "").map_or(true, |g| guids.contains(&g)));
+    for guid in guids {
+        let key = guid_property(*guid);
+        if !map.contains_key(&key) {
+            map.insert(key, value()?);
+        }
     }
 
-    Ok(resources)
+    Ok(())
+}
+
+// Same as `write_guid_map` for the elements of a SetProperty, see
+// `write_guid_set` for sets that may end up empty.
+pub(crate) fn update_guid_set(set: &mut Vec<Property>, guids: &[Guid]) {
+    set.retain(|p| get_guid(p, "").map_or(true, |g| guids.contains(&g)));
+    for guid in guids {
+        let property = guid_property(*guid);
+        if !set.contains(&property) {
+            set.push(property);
+        }
+    }
+}
+
+pub(crate) fn default_value(value_type: &str, path: &str) -> Result<Property, Error> {
+    match value_type {
+        "BoolProperty" => Ok(Property::from(BoolProperty::new(true))),
+        _ => Err(ParsingError::failed_cast(path, "BoolProperty", value_type).into()),
+    }
 }
 
 #[macro_export]
-macro_rules! get {
-    ($props:expr, $prop_name:expr, $prop_class:ident) => {
-        match $props
-            .get($prop_name)
-            .ok_or_else(|| ParsingError::missing_entry($prop_name))?
-        {
+macro_rules! cast {
+    ($property:expr, $prop_class:ident, $path:expr) => {
+        match $property {
             Property::$prop_class(p) => Ok(p),
-            _ => Err(ParsingError::failed_cast(stringify!($prop_class))),
+            p => Err(ParsingError::failed_cast(
+                &$path,
+                stringify!($prop_class),
+                $crate::error::property_type(p),
+            )),
         }
     };
-    ($res:expr, $guid:expr) => {
-        $res.get($guid)
-            .ok_or_else(|| ParsingError::missing_entry(&$guid.to_string()))
-    };
 }
 
 #[macro_export]
-macro_rules! get_mut {
+macro_rules! get {
     ($props:expr, $prop_name:expr, $prop_class:ident) => {
-        match $props
-            .get_mut($prop_name)
-            .ok_or_else(|| ParsingError::missing_entry($prop_name))?
-        {
-            Property::$prop_class(p) => Ok(p),
-            _ => Err(ParsingError::failed_cast(stringify!($prop_class))),
-        }
-    };
-    ($res:expr, $guid:expr) => {
-        $res.get_mut($guid)
-            .ok_or_else(|| ParsingError::missing_entry(&$guid.to_string()))
+        $crate::cast!(
+            $props
+                .get($prop_name)
+                .ok_or_else(|| ParsingError::missing_entry($prop_name))?,
+            $prop_class,
+            $prop_name
+        )
     };
 }
 
 #[macro_export]
-macro_rules! get_resource_mut {
-    ($map:expr, $guid:expr) => {
-        $map.get_mut(&Property::from(StructProperty::new(
-            Guid::from(0),
-            StructPropertyValue::Guid($guid),
-        )))
-        .ok_or_else(|| ParsingError::missing_entry(&$guid.to_string()))
+macro_rules! get_mut {
+    ($props:expr, $prop_name:expr, $prop_class:ident) => {
+        $crate::cast!(
+            $props
+                .get_mut($prop_name)
+                .ok_or_else(|| ParsingError::missing_entry($prop_name))?,
+            $prop_class,
+            $prop_name
+        )
     };
 }
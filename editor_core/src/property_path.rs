@@ -0,0 +1,346 @@
+use std::{fmt, mem};
+
+use gvas::{
+    properties::{struct_property::StructPropertyValue, Property},
+    types::Guid,
+    GvasFile,
+};
+
+use crate::{
+    error::{property_type, struct_type, PathError},
+    guid_property,
+};
+
+// Reads and edits properties anywhere in a save by path, e.g.
+// `SchematicSave.OwnedSchematics[3]` or `Resources.OwnedResources{<guid>}`.
+// `.Name` selects a struct field, `[n]` the nth element of an array, set or
+// map and `{guid}` a map entry by its Guid key.
+pub trait PropertyPath {
+    fn get_property(&self, path: &str) -> Result<&Property, PathError>;
+
+    fn get_property_mut(&mut self, path: &str) -> Result<&mut Property, PathError>;
+
+    // Replaces an existing property, returning the previous one.
+    fn set_property(&mut self, path: &str, property: Property) -> Result<Property, PathError>;
+
+    // Adds a struct field, map entry or array element. Existing fields and
+    // entries are replaced and returned, array elements are shifted along.
+    fn insert_property(
+        &mut self,
+        path: &str,
+        property: Property,
+    ) -> Result<Option<Property>, PathError>;
+
+    // Removing the last element of a SetProperty leaves an empty set, which
+    // gvas 0.7 panics on when the file is read back. Remove the set itself
+    // instead.
+    fn remove_property(&mut self, path: &str) -> Result<Property, PathError>;
+}
+
+enum Segment {
+    Field(String),
+    Index(usize),
+    Key(Guid),
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(name) => write!(f, ".{name}"),
+            Self::Index(i) => write!(f, "[{i}]"),
+            Self::Key(guid) => write!(f, "{{{guid}}}"),
+        }
+    }
+}
+
+impl Segment {
+    fn expected(&self) -> &'static str {
+        match self {
+            Self::Field(_) => "StructProperty",
+            Self::Index(_) => "ArrayProperty, SetProperty or MapProperty",
+            Self::Key(_) => "MapProperty",
+        }
+    }
+}
+
+impl PropertyPath for GvasFile {
+    fn get_property(&self, path: &str) -> Result<&Property, PathError> {
+        let (root, segments) = parse(path)?;
+        let mut property = self
+            .properties
+            .get(root)
+            .ok_or_else(|| PathError::Missing(root.to_string()))?;
+
+        let mut parent = root.to_string();
+        for segment in &segments {
+            property = child(property, segment, &parent)?;
+            parent += &segment.to_string();
+        }
+
+        Ok(property)
+    }
+
+    fn get_property_mut(&mut self, path: &str) -> Result<&mut Property, PathError> {
+        let (root, segments) = parse(path)?;
+        let mut property = self
+            .properties
+            .get_mut(root)
+            .ok_or_else(|| PathError::Missing(root.to_string()))?;
+
+        let mut parent = root.to_string();
+        for segment in &segments {
+            property = child_mut(property, segment, &parent)?;
+            parent += &segment.to_string();
+        }
+
+        Ok(property)
+    }
+
+    fn set_property(&mut self, path: &str, property: Property) -> Result<Property, PathError> {
+        Ok(mem::replace(self.get_property_mut(path)?, property))
+    }
+
+    fn insert_property(
+        &mut self,
+        path: &str,
+        property: Property,
+    ) -> Result<Option<Property>, PathError> {
+        let (root, segments) = parse(path)?;
+        let Some((last, _)) = segments.split_last() else {
+            return Ok(self.properties.insert(root.to_string(), property));
+        };
+
+        let parent = parent_path(path, last);
+        let container = self.get_property_mut(parent)?;
+        insert_child(container, last, property, parent)
+    }
+
+    fn remove_property(&mut self, path: &str) -> Result<Property, PathError> {
+        let (root, segments) = parse(path)?;
+        let Some((last, _)) = segments.split_last() else {
+            return self
+                .properties
+                .shift_remove(root)
+                .ok_or_else(|| PathError::Missing(root.to_string()));
+        };
+
+        let parent = parent_path(path, last);
+        let container = self.get_property_mut(parent)?;
+        remove_child(container, last, parent)
+    }
+}
+
+fn parse(path: &str) -> Result<(&str, Vec<Segment>), PathError> {
+    let invalid = || PathError::Syntax(path.to_string());
+    let delimiters = ['.', '[', '{'];
+
+    let end = path.find(delimiters).unwrap_or(path.len());
+    let (root, mut rest) = path.split_at(end);
+    if root.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        let (segment, tail) = if let Some(name) = rest.strip_prefix('.') {
+            let end = name.find(delimiters).unwrap_or(name.len());
+            if end == 0 {
+                return Err(invalid());
+            }
+            (Segment::Field(name[..end].to_string()), &name[end..])
+        } else if let Some(index) = rest.strip_prefix('[') {
+            let (index, tail) = index.split_once(']').ok_or_else(invalid)?;
+            (Segment::Index(index.parse().map_err(|_| invalid())?), tail)
+        } else if let Some(key) = rest.strip_prefix('{') {
+            let (key, tail) = key.split_once('}').ok_or_else(invalid)?;
+            (Segment::Key(key.parse().map_err(|_| invalid())?), tail)
+        } else {
+            return Err(invalid());
+        };
+
+        segments.push(segment);
+        rest = tail;
+    }
+
+    Ok((root, segments))
+}
+
+fn parent_path<'a>(path: &'a str, last: &Segment) -> &'a str {
+    // Cut at the input rather than re-displaying the segments, `[03]` and
+    // lower case Guids wouldn't round trip.
+    let start = match last {
+        Segment::Field(_) => path.rfind('.'),
+        Segment::Index(_) => path.rfind('['),
+        Segment::Key(_) => path.rfind('{'),
+    };
+    &path[..start.unwrap_or(path.len())]
+}
+
+fn mismatch(parent: &str, expected: &'static str, property: &Property) -> PathError {
+    let found = match property {
+        Property::StructProperty(p) => struct_type(&p.value).to_string(),
+        p => property_type(p).to_string(),
+    };
+
+    PathError::Mismatch {
+        path: parent.to_string(),
+        expected,
+        found,
+    }
+}
+
+fn child<'a>(
+    property: &'a Property,
+    segment: &Segment,
+    parent: &str,
+) -> Result<&'a Property, PathError> {
+    let missing = || PathError::Missing(format!("{parent}{segment}"));
+
+    match (segment, property) {
+        (Segment::Field(name), Property::StructProperty(p)) => match &p.value {
+            StructPropertyValue::CustomStruct(_, fields) => fields
+                .iter()
+                .find_map(|(n, p)| (n == name).then_some(p))
+                .ok_or_else(missing),
+            _ => Err(mismatch(parent, segment.expected(), property)),
+        },
+        (Segment::Index(i), Property::ArrayProperty(p)) => p.properties.get(*i).ok_or_else(missing),
+        (Segment::Index(i), Property::SetProperty(p)) => p.properties.get(*i).ok_or_else(missing),
+        (Segment::Index(i), Property::MapProperty(p)) => {
+            p.value.get_index(*i).map(|(_, v)| v).ok_or_else(missing)
+        }
+        (Segment::Key(guid), Property::MapProperty(p)) => {
+            p.value.get(&guid_property(*guid)).ok_or_else(missing)
+        }
+        _ => Err(mismatch(parent, segment.expected(), property)),
+    }
+}
+
+fn child_mut<'a>(
+    property: &'a mut Property,
+    segment: &Segment,
+    parent: &str,
+) -> Result<&'a mut Property, PathError> {
+    let missing = || PathError::Missing(format!("{parent}{segment}"));
+
+    match (segment, property) {
+        (Segment::Field(name), Property::StructProperty(p)) => match &mut p.value {
+            StructPropertyValue::CustomStruct(_, fields) => fields
+                .iter_mut()
+                .find_map(|(n, p)| (n == name).then_some(p))
+                .ok_or_else(missing),
+            v => Err(PathError::Mismatch {
+                path: parent.to_string(),
+                expected: segment.expected(),
+                found: struct_type(v).to_string(),
+            }),
+        },
+        (Segment::Index(i), Property::ArrayProperty(p)) => {
+            p.properties.get_mut(*i).ok_or_else(missing)
+        }
+        (Segment::Index(i), Property::SetProperty(p)) => {
+            p.properties.get_mut(*i).ok_or_else(missing)
+        }
+        (Segment::Index(i), Property::MapProperty(p)) => p
+            .value
+            .get_index_mut(*i)
+            .map(|(_, v)| v)
+            .ok_or_else(missing),
+        (Segment::Key(guid), Property::MapProperty(p)) => {
+            p.value.get_mut(&guid_property(*guid)).ok_or_else(missing)
+        }
+        (_, property) => Err(mismatch(parent, segment.expected(), property)),
+    }
+}
+
+fn insert_child(
+    container: &mut Property,
+    segment: &Segment,
+    property: Property,
+    parent: &str,
+) -> Result<Option<Property>, PathError> {
+    let missing = || PathError::Missing(format!("{parent}{segment}"));
+
+    let elements = match (segment, container) {
+        (Segment::Field(name), Property::StructProperty(p)) => match &mut p.value {
+            StructPropertyValue::CustomStruct(_, fields) => {
+                return match fields.iter_mut().find(|(n, _)| n == name) {
+                    Some((_, p)) => Ok(Some(mem::replace(p, property))),
+                    None => {
+                        fields.push((name.clone(), property));
+                        Ok(None)
+                    }
+                };
+            }
+            v => {
+                return Err(PathError::Mismatch {
+                    path: parent.to_string(),
+                    expected: segment.expected(),
+                    found: struct_type(v).to_string(),
+                })
+            }
+        },
+        (Segment::Key(guid), Property::MapProperty(p)) => {
+            return Ok(p.value.insert(guid_property(*guid), property));
+        }
+        (Segment::Index(_), Property::ArrayProperty(p)) => &mut p.properties,
+        (Segment::Index(_), Property::SetProperty(p)) => &mut p.properties,
+        (Segment::Index(_), container) => {
+            // Map entries need a key, insert them with `{guid}` instead.
+            return Err(mismatch(parent, "ArrayProperty or SetProperty", container));
+        }
+        (_, container) => return Err(mismatch(parent, segment.expected(), container)),
+    };
+
+    match segment {
+        Segment::Index(i) if *i <= elements.len() => {
+            elements.insert(*i, property);
+            Ok(None)
+        }
+        _ => Err(missing()),
+    }
+}
+
+fn remove_child(
+    container: &mut Property,
+    segment: &Segment,
+    parent: &str,
+) -> Result<Property, PathError> {
+    let missing = || PathError::Missing(format!("{parent}{segment}"));
+
+    match (segment, container) {
+        (Segment::Field(name), Property::StructProperty(p)) => match &mut p.value {
+            StructPropertyValue::CustomStruct(_, fields) => {
+                let index = fields
+                    .iter()
+                    .position(|(n, _)| n == name)
+                    .ok_or_else(missing)?;
+                Ok(fields.remove(index).1)
+            }
+            v => Err(PathError::Mismatch {
+                path: parent.to_string(),
+                expected: segment.expected(),
+                found: struct_type(v).to_string(),
+            }),
+        },
+        (Segment::Index(i), Property::ArrayProperty(p)) if *i < p.properties.len() => {
+            Ok(p.properties.remove(*i))
+        }
+        (Segment::Index(i), Property::SetProperty(p)) if *i < p.properties.len() => {
+            Ok(p.properties.remove(*i))
+        }
+        (Segment::Index(_), Property::ArrayProperty(_) | Property::SetProperty(_)) => {
+            Err(missing())
+        }
+        (Segment::Index(i), Property::MapProperty(p)) => p
+            .value
+            .shift_remove_index(*i)
+            .map(|(_, v)| v)
+            .ok_or_else(missing),
+        (Segment::Key(guid), Property::MapProperty(p)) => p
+            .value
+            .shift_remove(&guid_property(*guid))
+            .ok_or_else(missing),
+        (_, container) => Err(mismatch(parent, segment.expected(), container)),
+    }
+}
@@ -5,16 +5,85 @@ use crate::registry::{CosmeticType::*, Dwarf::*, OverclockType::*, Resource::*,
 use gvas::types::Guid;
 use lazy_static::lazy_static;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Resource {
     Credits,
+    PerkPoints,
+    Magnite,
+    Bismor,
     Croppa,
     Umanite,
-    Bismor,
     Jadiz,
-    Magnite,
     EnorPearl,
+    StarchNut,
+    YeastCone,
+    MaltStar,
+    BarleyBulb,
+    ErrorCubes,
+    DataCells,
+    BlankCores,
+    Phazyonite,
+}
+
+impl Resource {
+    pub const ALL: [Resource; 16] = [
+        Credits, PerkPoints, Magnite, Bismor, Croppa, Umanite, Jadiz, EnorPearl, StarchNut,
+        YeastCone, MaltStar, BarleyBulb, ErrorCubes, DataCells, BlankCores, Phazyonite,
+    ];
+
+    // Credits and perk points are top-level IntProperties, not OwnedResources.
+    pub fn guid(self) -> Option<Guid> {
+        match self {
+            Credits | PerkPoints => None,
+            Magnite => Some(MAGNITE),
+            Bismor => Some(BISMOR),
+            Croppa => Some(CROPPA),
+            Umanite => Some(UMANITE),
+            Jadiz => Some(JADIZ),
+            EnorPearl => Some(ENOR_PEARL),
+            StarchNut => Some(STARCH_NUT),
+            YeastCone => Some(YEAST_CONE),
+            MaltStar => Some(MALT_STAR),
+            BarleyBulb => Some(BARLEY_BULB),
+            ErrorCubes => Some(ERROR_CUBES),
+            DataCells => Some(DATA_CELLS),
+            BlankCores => Some(BLANK_CORES),
+            Phazyonite => Some(PHAZYONITE),
+        }
+    }
+
+    pub fn from_guid(guid: Guid) -> Option<Self> {
+        Self::ALL.into_iter().find(|r| r.guid() == Some(guid))
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Credits => "Credits",
+            PerkPoints => "Perk points",
+            Magnite => "Magnite",
+            Bismor => "Bismor",
+            Croppa => "Croppa",
+            Umanite => "Umanite",
+            Jadiz => "Jadiz",
+            EnorPearl => "Enor pearl",
+            StarchNut => "Starch nut",
+            YeastCone => "Yeast cone",
+            MaltStar => "Malt star",
+            BarleyBulb => "Barley bulb",
+            ErrorCubes => "Error cubes",
+            DataCells => "Data cells",
+            BlankCores => "Blank cores",
+            Phazyonite => "Phazyonite",
+        }
+    }
+
+    pub fn max(self) -> f64 {
+        match self {
+            Credits | PerkPoints => MAX_I32 as f64,
+            _ => MAX_F32 as f64,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -152,7 +221,7 @@ pub const MAX_LEVEL: i32 = 25;
 pub const MAX_F32: f32 = 268_435_456.0;
 pub const MAX_I32: i32 = 268_435_456;
 
-#[derive(Debug, PartialEq, Clone, Eq)]
+#[derive(Debug, PartialEq, Clone, Copy, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Dwarf {
     Engineer,
@@ -161,6 +230,24 @@ pub enum Dwarf {
     Scout,
 }
 
+impl Dwarf {
+    pub const ALL: [Dwarf; 4] = [Engineer, Driller, Gunner, Scout];
+
+    // SavegameID of the dwarf's CharacterSaves entry.
+    pub fn guid(self) -> Guid {
+        match self {
+            Engineer => ENGINEER,
+            Gunner => GUNNER,
+            Driller => DRILLER,
+            Scout => SCOUT,
+        }
+    }
+
+    pub fn from_guid(guid: Guid) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.guid() == guid)
+    }
+}
+
 pub(crate) fn get_hints() -> HashMap<String, String> {
     let mut hints = HashMap::new();
     hints.insert(
@@ -256,11 +343,6 @@ pub(crate) fn get_hints() -> HashMap<String, String> {
         "Guid".to_string(),
     );
 
-    hints.insert(
-        "FSDEventRewardsSave.StructProperty.EventsSeen.SetProperty.StructProperty".to_string(),
-        "Guid".to_string(),
-    );
-
     hints.insert(
         "FSDEventRewardsSave.StructProperty.PopupsSeen.SetProperty.StructProperty".to_string(),
         "Guid".to_string(),
@@ -0,0 +1,156 @@
+use std::ops::{Deref, DerefMut};
+
+use gvas::{
+    properties::{int_property::FloatProperty, Property},
+    types::Guid,
+    GvasFile,
+};
+use indexmap::IndexMap;
+
+use crate::{
+    cast, custom_struct, custom_struct_mut,
+    error::{Error, ParsingError},
+    get_field, get_field_mut, get_guid, guid_property,
+    registry::MAX_F32,
+};
+
+const PATH: &str = "Resources.OwnedResources";
+
+// View over the Resources.OwnedResources map, amounts keyed by resource Guid.
+// Entries are checked once when the view is created, so lookups can't fail.
+#[derive(Debug)]
+pub struct OwnedResources<M> {
+    map: M,
+}
+
+impl<'a> OwnedResources<&'a IndexMap<Property, Property>> {
+    pub fn new(gvas: &'a GvasFile) -> Result<Self, Error> {
+        let resources = custom_struct(
+            gvas.properties
+                .get("Resources")
+                .ok_or_else(|| ParsingError::missing_entry("Resources"))?,
+            "Resources",
+        )?;
+        let map = &cast!(
+            get_field(resources, "Resources", "OwnedResources")?,
+            MapProperty,
+            PATH
+        )?
+        .value;
+        validate(map)?;
+
+        Ok(Self { map })
+    }
+}
+
+impl<'a> OwnedResources<&'a mut IndexMap<Property, Property>> {
+    pub fn new_mut(gvas: &'a mut GvasFile) -> Result<Self, Error> {
+        let resources = custom_struct_mut(
+            gvas.properties
+                .get_mut("Resources")
+                .ok_or_else(|| ParsingError::missing_entry("Resources"))?,
+            "Resources",
+        )?;
+        let map = &mut cast!(
+            get_field_mut(resources, "Resources", "OwnedResources")?,
+            MapProperty,
+            PATH
+        )?
+        .value;
+        validate(map)?;
+
+        Ok(Self { map })
+    }
+}
+
+impl<M: Deref<Target = IndexMap<Property, Property>>> OwnedResources<M> {
+    pub fn get(&self, guid: Guid) -> Option<f32> {
+        self.map.get(&guid_property(guid)).and_then(amount)
+    }
+
+    pub fn contains(&self, guid: Guid) -> bool {
+        self.map.contains_key(&guid_property(guid))
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    // Entries in the order they are stored in the save.
+    pub fn iter(&self) -> impl Iterator<Item = (Guid, f32)> + '_ {
+        self.map
+            .iter()
+            .filter_map(|(k, v)| Some((get_guid(k, PATH).ok()?, amount(v)?)))
+    }
+}
+
+impl<M: DerefMut<Target = IndexMap<Property, Property>>> OwnedResources<M> {
+    pub fn get_mut(&mut self, guid: Guid) -> Option<&mut f32> {
+        match self.map.get_mut(&guid_property(guid)) {
+            Some(Property::FloatProperty(p)) => Some(&mut p.value.0),
+            _ => None,
+        }
+    }
+
+    // Updates the amount in place, or appends a new entry when the account
+    // never had the resource. Returns the previous amount.
+    pub fn insert(&mut self, guid: Guid, amount: f32) -> Option<f32> {
+        self.map
+            .insert(
+                guid_property(guid),
+                Property::from(FloatProperty::new(amount)),
+            )
+            .as_ref()
+            .and_then(self::amount)
+    }
+
+    // Used by the sections' `write`. Resources the account never had are only
+    // added once non-zero, so an unedited save is written back as it was.
+    pub(crate) fn set_amount(&mut self, guid: Guid, amount: f32) {
+        match self.get_mut(guid) {
+            Some(a) => *a = amount,
+            None if amount != 0.0 => {
+                self.insert(guid, amount);
+            }
+            None => (),
+        }
+    }
+
+    pub fn remove(&mut self, guid: Guid) -> Option<f32> {
+        self.map
+            .shift_remove(&guid_property(guid))
+            .as_ref()
+            .and_then(amount)
+    }
+}
+
+fn validate(map: &IndexMap<Property, Property>) -> Result<(), Error> {
+    for (i, (k, v)) in map.iter().enumerate() {
+        let guid = get_guid(k, &format!("{PATH}[{i}]"))?;
+        cast!(v, FloatProperty, format!("{PATH}{{{guid}}}"))?;
+    }
+
+    Ok(())
+}
+
+// Amounts the game accepts for a resource, used by the sections' `validate`.
+pub(crate) fn check_amount(guid: Guid, amount: f32) -> Result<(), Error> {
+    if !(0.0..=MAX_F32).contains(&amount) {
+        let path = format!("{PATH}{{{guid}}}");
+        let reason = format!("{amount} is not in 0..={MAX_F32}");
+        return Err(ParsingError::invalid_value(&path, &reason).into());
+    }
+
+    Ok(())
+}
+
+fn amount(property: &Property) -> Option<f32> {
+    match property {
+        Property::FloatProperty(p) => Some(p.value.0),
+        _ => None,
+    }
+}
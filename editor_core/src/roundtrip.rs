@@ -0,0 +1,247 @@
+use gvas::{properties::Property, GvasFile};
+use indexmap::IndexMap;
+use thiserror::Error;
+
+use crate::{error::Error, gvas_bytes, read_gvas_bytes, save_file::SaveFile};
+
+// "None" FString followed by 4 bytes of padding.
+const TERMINATOR_LEN: usize = 13;
+
+#[derive(Error, Debug)]
+pub enum RoundTripMismatch {
+    #[error(transparent)]
+    Load(#[from] Error),
+    #[error("Output differs from input at {path} (byte offset {offset})")]
+    Mismatch { path: String, offset: usize },
+}
+
+/// Loads `bytes` into a [`SaveFile`], writes it back without edits and checks
+/// that the output is identical to the input.
+pub fn verify_roundtrip(bytes: &[u8]) -> Result<(), RoundTripMismatch> {
+    let original = read_gvas_bytes(bytes)?;
+    let save = SaveFile::from_gvas(&original)?;
+
+    let mut written = original.clone();
+    let out = save.to_bytes(&mut written)?;
+
+    let offset = match bytes.iter().zip(&out).position(|(a, b)| a != b) {
+        Some(offset) => offset,
+        None if bytes.len() == out.len() => return Ok(()),
+        None => bytes.len().min(out.len()),
+    };
+
+    let path = match diff_properties(&original.properties, &written.properties) {
+        Some(path) => path,
+        None => locate(&written, offset)?,
+    };
+
+    Err(RoundTripMismatch::Mismatch { path, offset })
+}
+
+fn diff_properties(
+    a: &IndexMap<String, Property>,
+    b: &IndexMap<String, Property>,
+) -> Option<String> {
+    for pair in a.iter().zip(b.iter()) {
+        let ((a_name, a), (b_name, b)) = pair;
+        if a_name != b_name {
+            return Some(a_name.to_owned());
+        }
+        if let Some(path) = diff(a, b) {
+            return Some(format!("{a_name}{path}"));
+        }
+    }
+
+    let len = a.len().min(b.len());
+    a.get_index(len)
+        .or_else(|| b.get_index(len))
+        .map(|(name, _)| name.to_owned())
+}
+
+// Returns the path below `a` at which `a` and `b` first differ. Unlike `==`,
+// this treats maps as ordered, because their order is part of the file.
+fn diff(a: &Property, b: &Property) -> Option<String> {
+    match (a, b) {
+        (Property::StructProperty(a), Property::StructProperty(b)) => {
+            match (a.value.get_custom_struct(), b.value.get_custom_struct()) {
+                (Some(a), Some(b)) if a.0 == b.0 => {
+                    for (a, b) in a.1.iter().zip(b.1) {
+                        if a.0 != b.0 {
+                            return Some(format!(".{}", a.0));
+                        }
+                        if let Some(path) = diff(&a.1, &b.1) {
+                            return Some(format!(".{}{path}", a.0));
+                        }
+                    }
+
+                    (a.1.len() != b.1.len()).then(String::new)
+                }
+                _ => (a != b).then(String::new),
+            }
+        }
+        (Property::ArrayProperty(a), Property::ArrayProperty(b)) => {
+            diff_elements(&a.properties, &b.properties).or_else(|| (a != b).then(String::new))
+        }
+        (Property::SetProperty(a), Property::SetProperty(b)) => {
+            diff_elements(&a.properties, &b.properties).or_else(|| (a != b).then(String::new))
+        }
+        (Property::MapProperty(a), Property::MapProperty(b)) => {
+            for (idx, (a, b)) in a.value.iter().zip(&b.value).enumerate() {
+                let key = key_segment(a.0, idx);
+                if a.0 != b.0 {
+                    return Some(key);
+                }
+                if let Some(path) = diff(a.1, b.1) {
+                    return Some(format!("{key}{path}"));
+                }
+            }
+
+            (a.value.len() != b.value.len() || a != b).then(String::new)
+        }
+        _ => (a != b).then(String::new),
+    }
+}
+
+fn diff_elements(a: &[Property], b: &[Property]) -> Option<String> {
+    for (idx, (a, b)) in a.iter().zip(b).enumerate() {
+        if let Some(path) = diff(a, b) {
+            return Some(format!("[{idx}]{path}"));
+        }
+    }
+
+    (a.len() != b.len()).then(|| format!("[{}]", a.len().min(b.len())))
+}
+
+fn key_segment(key: &Property, idx: usize) -> String {
+    match key.get_struct().and_then(|k| k.value.get_guid()) {
+        Some(guid) => format!("{{{guid}}}"),
+        None => format!("[{idx}]"),
+    }
+}
+
+// Finds the top level property that was serialized at `offset`.
+fn locate(gvas: &GvasFile, offset: usize) -> Result<String, Error> {
+    let mut partial = GvasFile {
+        header: gvas.header.clone(),
+        properties: IndexMap::new(),
+    };
+    if offset < gvas_bytes(&partial)?.len() - TERMINATOR_LEN {
+        return Ok("header".to_owned());
+    }
+
+    for (name, property) in &gvas.properties {
+        partial
+            .properties
+            .insert(name.to_owned(), property.to_owned());
+        if offset < gvas_bytes(&partial)?.len() - TERMINATOR_LEN {
+            return Ok(name.to_owned());
+        }
+    }
+
+    Ok("None".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use gvas::{
+        properties::{
+            array_property::ArrayProperty,
+            int_property::FloatProperty,
+            map_property::MapProperty,
+            struct_property::{StructProperty, StructPropertyValue},
+        },
+        types::Guid,
+    };
+
+    use crate::{
+        guid_property,
+        registry::{BISMOR, CROPPA, MAGNITE},
+    };
+
+    use super::*;
+
+    fn custom_struct(name: &str, field: &str, value: Property) -> Property {
+        Property::from(StructProperty::new(
+            Guid::from(0),
+            StructPropertyValue::CustomStruct(name.to_string(), vec![(field.to_string(), value)]),
+        ))
+    }
+
+    fn properties(resources: &[(Guid, f32)], schematics: &[Guid]) -> IndexMap<String, Property> {
+        let resources = resources
+            .iter()
+            .map(|(guid, amount)| {
+                (
+                    guid_property(*guid),
+                    Property::from(FloatProperty::new(*amount)),
+                )
+            })
+            .collect();
+        let resources = MapProperty::new(
+            "StructProperty".to_string(),
+            "FloatProperty".to_string(),
+            0,
+            resources,
+        );
+        let schematics = ArrayProperty::new(
+            "StructProperty".to_string(),
+            Some((
+                "OwnedSchematics".to_string(),
+                "Guid".to_string(),
+                Guid::from(0),
+            )),
+            schematics.iter().copied().map(guid_property).collect(),
+        );
+
+        IndexMap::from([
+            (
+                "Resources".to_string(),
+                custom_struct("Resources", "OwnedResources", Property::from(resources)),
+            ),
+            (
+                "SchematicSave".to_string(),
+                custom_struct(
+                    "SchematicSave",
+                    "OwnedSchematics",
+                    Property::from(schematics),
+                ),
+            ),
+        ])
+    }
+
+    #[test]
+    fn the_first_differing_property_is_reported_by_path() {
+        let schematics = [Guid::from(1), Guid::from(2), Guid::from(3)];
+        let original = properties(&[(MAGNITE, 1.0), (BISMOR, 2.0)], &schematics);
+
+        assert_eq!(diff_properties(&original, &original.clone()), None);
+
+        let changed_value = properties(&[(MAGNITE, 1.0), (BISMOR, 5.0)], &schematics);
+        assert_eq!(
+            diff_properties(&original, &changed_value),
+            Some(format!("Resources.OwnedResources{{{BISMOR}}}"))
+        );
+
+        let changed_element = properties(
+            &[(MAGNITE, 1.0), (BISMOR, 2.0)],
+            &[Guid::from(1), Guid::from(4), Guid::from(3)],
+        );
+        assert_eq!(
+            diff_properties(&original, &changed_element),
+            Some("SchematicSave.OwnedSchematics[1]".to_string())
+        );
+
+        // Equal as maps, but written in a different order.
+        let reordered = properties(&[(BISMOR, 2.0), (MAGNITE, 1.0)], &schematics);
+        assert_eq!(
+            diff_properties(&original, &reordered),
+            Some(format!("Resources.OwnedResources{{{MAGNITE}}}"))
+        );
+
+        let added = properties(&[(MAGNITE, 1.0), (BISMOR, 2.0), (CROPPA, 3.0)], &schematics);
+        assert_eq!(
+            diff_properties(&original, &added),
+            Some("Resources.OwnedResources".to_string())
+        );
+    }
+}
@@ -1,12 +1,14 @@
 use gvas::GvasFile;
 
 use crate::{
-    error::{Error, ParsingError},
-    get, get_owned_resources,
+    error::Error,
+    resources::{check_amount, OwnedResources},
 };
 
 use crate::registry::{BARLEY_BULB, MALT_STAR, STARCH_NUT, YEAST_CONE};
 
+use super::section::SaveSection;
+
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Brewing {
@@ -16,14 +18,18 @@ pub struct Brewing {
     pub barley_bulb: f32,
 }
 
-impl Brewing {
-    pub(crate) fn from_gvas(gvas: &GvasFile) -> Result<Self, Error> {
-        let owned_resources = get_owned_resources(gvas)?;
+impl SaveSection for Brewing {
+    fn name() -> &'static str {
+        "brewing"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let owned_resources = OwnedResources::new(gvas)?;
 
-        let starch_nut = get!(owned_resources, &STARCH_NUT)?.value.0;
-        let yeast_cone = get!(owned_resources, &YEAST_CONE)?.value.0;
-        let malt_star = get!(owned_resources, &MALT_STAR)?.value.0;
-        let barley_bulb = get!(owned_resources, &BARLEY_BULB)?.value.0;
+        let starch_nut = owned_resources.get(STARCH_NUT).unwrap_or_default();
+        let yeast_cone = owned_resources.get(YEAST_CONE).unwrap_or_default();
+        let malt_star = owned_resources.get(MALT_STAR).unwrap_or_default();
+        let barley_bulb = owned_resources.get(BARLEY_BULB).unwrap_or_default();
 
         Ok(Self {
             starch_nut,
@@ -32,4 +38,27 @@ impl Brewing {
             barley_bulb,
         })
     }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let mut resources = OwnedResources::new_mut(gvas)?;
+        resources.set_amount(STARCH_NUT, self.starch_nut);
+        resources.set_amount(YEAST_CONE, self.yeast_cone);
+        resources.set_amount(MALT_STAR, self.malt_star);
+        resources.set_amount(BARLEY_BULB, self.barley_bulb);
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        for (guid, amount) in [
+            (STARCH_NUT, self.starch_nut),
+            (YEAST_CONE, self.yeast_cone),
+            (MALT_STAR, self.malt_star),
+            (BARLEY_BULB, self.barley_bulb),
+        ] {
+            check_amount(guid, amount)?;
+        }
+
+        Ok(())
+    }
 }
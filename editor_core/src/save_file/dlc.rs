@@ -0,0 +1,58 @@
+use gvas::{types::Guid, GvasFile};
+
+use crate::{error::Error, get_guid_set, get_guids, write_guid_set};
+
+use super::section::SaveSection;
+
+// Announced DLCs by GUID. Naming them would take a DLC table generated from
+// the game assets, nothing generates one.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dlc {
+    pub announced: Vec<Guid>,
+}
+
+impl Dlc {
+    pub fn is_announced(&self, dlc: Guid) -> bool {
+        self.announced.contains(&dlc)
+    }
+
+    pub fn announce(&mut self, dlc: Guid) -> bool {
+        if self.is_announced(dlc) {
+            return false;
+        }
+
+        self.announced.push(dlc);
+        true
+    }
+
+    pub fn remove(&mut self, dlc: Guid) -> bool {
+        let len = self.announced.len();
+        self.announced.retain(|d| *d != dlc);
+
+        len != self.announced.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.announced.clear();
+    }
+}
+
+impl SaveSection for Dlc {
+    fn name() -> &'static str {
+        "dlc"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let announced = match get_guid_set(gvas, "GameDLCSave", Some("AnnouncedIDs"))? {
+            Some(set) => get_guids(set, "GameDLCSave.AnnouncedIDs")?,
+            None => Vec::new(),
+        };
+
+        Ok(Self { announced })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        write_guid_set(gvas, "GameDLCSave", Some("AnnouncedIDs"), &self.announced)
+    }
+}
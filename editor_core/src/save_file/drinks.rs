@@ -0,0 +1,54 @@
+use gvas::{types::Guid, GvasFile};
+
+use crate::{error::Error, get_guid_set, get_guids, write_guid_set};
+
+use super::section::SaveSection;
+
+// Drink names aren't available, the asset dumps in guids_generator don't
+// include the Abyss Bar, so drinks are handled by GUID.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Drinks {
+    pub unlocked: Vec<Guid>,
+}
+
+impl Drinks {
+    pub fn is_unlocked(&self, drink: Guid) -> bool {
+        self.unlocked.contains(&drink)
+    }
+
+    pub fn unlock(&mut self, drink: Guid) -> bool {
+        if self.is_unlocked(drink) {
+            return false;
+        }
+
+        self.unlocked.push(drink);
+        true
+    }
+
+    pub fn lock(&mut self, drink: Guid) -> bool {
+        let len = self.unlocked.len();
+        self.unlocked.retain(|d| *d != drink);
+
+        len != self.unlocked.len()
+    }
+}
+
+impl SaveSection for Drinks {
+    fn name() -> &'static str {
+        "drinks"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let unlocked = match get_guid_set(gvas, "Drinks", Some("UnlockedDrinks"))? {
+            Some(set) => get_guids(set, "Drinks.UnlockedDrinks")?,
+            None => Vec::new(),
+        };
+
+        Ok(Self { unlocked })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        write_guid_set(gvas, "Drinks", Some("UnlockedDrinks"), &self.unlocked)
+    }
+}
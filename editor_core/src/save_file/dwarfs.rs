@@ -1,11 +1,15 @@
-use gvas::{properties::Property, GvasFile};
+use gvas::{properties::Property, types::Guid, GvasFile};
 
 use crate::{
+    custom_struct,
     error::{Error, ParsingError},
-    get,
+    get, get_field, get_guid, get_int, get_mut,
     registry::{DRILLER, ENGINEER, GUNNER, MAX_LEVEL, PROMOTIONS, SCOUT, XP_TABLE},
+    set_int,
 };
 
+use super::section::SaveSection;
+
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rank {
@@ -16,12 +20,10 @@ pub struct Rank {
 
 impl Rank {
     pub fn new(xp: i32, times_retired: i32) -> Self {
-        let promotion = if times_retired > 18 {
-            PROMOTIONS.last().unwrap()
-        } else {
-            &PROMOTIONS[times_retired as usize]
-        }
-        .to_owned();
+        let promotion = usize::try_from(times_retired)
+            .unwrap_or(0)
+            .min(PROMOTIONS.len() - 1);
+        let promotion = PROMOTIONS[promotion].to_owned();
 
         Self {
             xp,
@@ -31,7 +33,7 @@ impl Rank {
     }
 
     pub fn xp_to_level(&self) -> (i32, i32) {
-        for (idx, _) in XP_TABLE.iter().enumerate() {
+        for (idx, _) in XP_TABLE.iter().enumerate().skip(1) {
             if self.xp < XP_TABLE[idx] {
                 return (idx as i32, self.xp - XP_TABLE[idx - 1]);
             }
@@ -48,50 +50,106 @@ pub struct Characters {
     pub driller: Rank,
     pub gunner: Rank,
     pub scout: Rank,
+    // SavegameIDs of character entries that aren't one of the four classes.
+    pub unknown: Vec<Guid>,
 }
 
-impl Characters {
-    pub(crate) fn from_gvas(gvas: &GvasFile) -> Result<Self, Error> {
-        let Self {
-            mut engineer,
-            mut driller,
-            mut gunner,
-            mut scout,
-        } = Self::default();
+impl SaveSection for Characters {
+    fn name() -> &'static str {
+        "dwarfs"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let mut characters = Self::default();
+        let mut seen = Vec::new();
 
         let props = &gvas.properties;
         let character_save = get!(props, "CharacterSaves", ArrayProperty)?;
-        character_save
-            .properties
-            .iter()
-            .filter_map(|p| p.get_struct())
-            .filter_map(|p| p.value.get_custom_struct())
-            .map(|p| p.1)
-            .map(|p| {
-                p.iter()
-                    .filter(|f| f.0 == "SavegameID" || f.0 == "XP" || f.0 == "TimesRetired")
-                    .collect::<Vec<_>>()
-            })
-            .for_each(|d| {
-                let guid = d[0].1.get_struct().unwrap().value.get_guid().unwrap();
-                let xp = d[1].1.get_int().unwrap();
-                let times_retired = d[2].1.get_int().unwrap();
-
-                let rank = Rank::new(xp.value, times_retired.value);
-                match *guid {
-                    ENGINEER => engineer = rank,
-                    DRILLER => driller = rank,
-                    GUNNER => gunner = rank,
-                    SCOUT => scout = rank,
-                    _ => (),
-                }
-            });
-
-        Ok(Self {
-            engineer,
-            driller,
-            gunner,
-            scout,
-        })
+        for (i, character) in character_save.properties.iter().enumerate() {
+            let path = format!("CharacterSaves[{i}]");
+            let fields = custom_struct(character, &path)?;
+
+            let id = get_guid(
+                get_field(fields, &path, "SavegameID")?,
+                &format!("{path}.SavegameID"),
+            )?;
+            // `write` updates the first entry of a class, so later duplicates
+            // are ignored here too.
+            if seen.contains(&id) {
+                continue;
+            }
+            seen.push(id);
+
+            let rank = Rank::new(
+                get_int(fields, &path, "XP")?,
+                get_int(fields, &path, "TimesRetired")?,
+            );
+
+            match id {
+                ENGINEER => characters.engineer = rank,
+                DRILLER => characters.driller = rank,
+                GUNNER => characters.gunner = rank,
+                SCOUT => characters.scout = rank,
+                _ => characters.unknown.push(id),
+            }
+        }
+
+        Ok(characters)
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let props = &mut gvas.properties;
+        let character_save = get_mut!(props, "CharacterSaves", ArrayProperty)?;
+
+        for (class, id, rank) in [
+            ("Engineer", ENGINEER, &self.engineer),
+            ("Driller", DRILLER, &self.driller),
+            ("Gunner", GUNNER, &self.gunner),
+            ("Scout", SCOUT, &self.scout),
+        ] {
+            let (i, character) = character_save
+                .properties
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(i, p)| {
+                    Some((i, p.get_struct_mut()?.value.get_custom_struct_mut()?.1))
+                })
+                .find(|(_, p)| {
+                    p.iter().any(|f| {
+                        f.0 == "SavegameID"
+                            && f.1.get_struct().and_then(|s| s.value.get_guid()) == Some(&id)
+                    })
+                })
+                .ok_or_else(|| ParsingError::missing_entry(&format!("CharacterSaves.{class}")))?;
+
+            let path = format!("CharacterSaves[{i}]");
+            set_int(character, &path, "XP", rank.xp)?;
+            set_int(character, &path, "TimesRetired", rank.times_retired)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        let max_xp = XP_TABLE[XP_TABLE.len() - 1];
+        for (id, rank) in [
+            (ENGINEER, &self.engineer),
+            (DRILLER, &self.driller),
+            (GUNNER, &self.gunner),
+            (SCOUT, &self.scout),
+        ] {
+            let path = format!("CharacterSaves{{{id}}}");
+            if !(0..=max_xp).contains(&rank.xp) {
+                let reason = format!("{} is not in 0..={max_xp}", rank.xp);
+                return Err(ParsingError::invalid_value(&format!("{path}.XP"), &reason).into());
+            }
+            if rank.times_retired < 0 {
+                let reason = format!("{} is negative", rank.times_retired);
+                let path = format!("{path}.TimesRetired");
+                return Err(ParsingError::invalid_value(&path, &reason).into());
+            }
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,63 @@
+use gvas::{properties::Property, types::Guid, GvasFile};
+
+use crate::{
+    cast,
+    error::{Error, ParsingError},
+    get_guid,
+};
+
+use super::section::SaveSection;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnemyKills {
+    pub enemy: Guid,
+    pub kills: i32,
+}
+
+// Enemies are only known by GUID, guids_generator has no enemy dump to name
+// them from.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnemyStats {
+    pub enemies: Vec<EnemyKills>,
+}
+
+impl EnemyStats {
+    pub fn get(&self, enemy: Guid) -> Option<&EnemyKills> {
+        self.enemies.iter().find(|e| e.enemy == enemy)
+    }
+
+    pub fn total(&self) -> i64 {
+        self.enemies.iter().map(|e| e.kills as i64).sum()
+    }
+}
+
+impl SaveSection for EnemyStats {
+    fn name() -> &'static str {
+        "enemy_stats"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let props = &gvas.properties;
+        let enemies_killed = match props.get("EnemiesKilled") {
+            Some(p) => &cast!(p, MapProperty, "EnemiesKilled")?.value,
+            None => return Ok(Self::default()),
+        };
+
+        let mut enemies = Vec::new();
+        for (i, (k, v)) in enemies_killed.iter().enumerate() {
+            let enemy = get_guid(k, &format!("EnemiesKilled[{i}]"))?;
+            let kills = cast!(v, IntProperty, format!("EnemiesKilled{{{enemy}}}"))?.value;
+
+            enemies.push(EnemyKills { enemy, kills });
+        }
+
+        Ok(Self { enemies })
+    }
+
+    // Read only, enemy stats are kept for display.
+    fn write(&self, _gvas: &mut GvasFile) -> Result<(), Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use gvas::{types::Guid, GvasFile};
+
+use crate::{error::Error, get_guid_set, get_guids, write_guid_set};
+
+use super::section::SaveSection;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventRewards {
+    pub events_seen: Vec<Guid>,
+    pub popups_seen: Vec<Guid>,
+}
+
+impl EventRewards {
+    pub fn is_event_seen(&self, event: Guid) -> bool {
+        self.events_seen.contains(&event)
+    }
+
+    pub fn is_popup_seen(&self, popup: Guid) -> bool {
+        self.popups_seen.contains(&popup)
+    }
+
+    pub fn set_event_seen(&mut self, event: Guid, seen: bool) {
+        set_seen(&mut self.events_seen, event, seen);
+    }
+
+    pub fn set_popup_seen(&mut self, popup: Guid, seen: bool) {
+        set_seen(&mut self.popups_seen, popup, seen);
+    }
+}
+
+impl SaveSection for EventRewards {
+    fn name() -> &'static str {
+        "event_rewards"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        Ok(Self {
+            events_seen: read_set(gvas, "EventsSeen")?,
+            popups_seen: read_set(gvas, "PopupsSeen")?,
+        })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let name = "FSDEventRewardsSave";
+        write_guid_set(gvas, name, Some("EventsSeen"), &self.events_seen)?;
+        write_guid_set(gvas, name, Some("PopupsSeen"), &self.popups_seen)
+    }
+}
+
+fn read_set(gvas: &GvasFile, field: &str) -> Result<Vec<Guid>, Error> {
+    match get_guid_set(gvas, "FSDEventRewardsSave", Some(field))? {
+        Some(set) => get_guids(set, &format!("FSDEventRewardsSave.{field}")),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn set_seen(seen: &mut Vec<Guid>, guid: Guid, value: bool) {
+    match (seen.contains(&guid), value) {
+        (false, true) => seen.push(guid),
+        (true, false) => seen.retain(|g| *g != guid),
+        _ => (),
+    }
+}
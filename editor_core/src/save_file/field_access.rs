@@ -0,0 +1,256 @@
+use gvas::types::Guid;
+
+use crate::{
+    error::FieldError,
+    registry::{Dwarf, Resource, MAX_I32, XP_TABLE},
+};
+
+use super::{dwarfs::Rank, SaveFile};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Value {
+    Int(i32),
+    Float(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValueType {
+    Int,
+    Float,
+}
+
+// An editable field as listed by `SaveFile::paths`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Field {
+    pub path: String,
+    pub ty: ValueType,
+    pub min: f64,
+    pub max: f64,
+}
+
+const RESOURCES: [(&str, Resource); 16] = [
+    ("minerals.magnite", Resource::Magnite),
+    ("minerals.bismor", Resource::Bismor),
+    ("minerals.croppa", Resource::Croppa),
+    ("minerals.umanite", Resource::Umanite),
+    ("minerals.jadiz", Resource::Jadiz),
+    ("minerals.enor_pearl", Resource::EnorPearl),
+    ("brewing.starch_nut", Resource::StarchNut),
+    ("brewing.yeast_cone", Resource::YeastCone),
+    ("brewing.malt_star", Resource::MaltStar),
+    ("brewing.barley_bulb", Resource::BarleyBulb),
+    ("miscellaneous.credits", Resource::Credits),
+    ("miscellaneous.perk_points", Resource::PerkPoints),
+    ("miscellaneous.error_cubes", Resource::ErrorCubes),
+    ("miscellaneous.data_cells", Resource::DataCells),
+    ("miscellaneous.blank_cores", Resource::BlankCores),
+    ("miscellaneous.phazyonite", Resource::Phazyonite),
+];
+
+#[derive(Clone, Copy)]
+enum Target {
+    Resource(Resource),
+    Xp(Dwarf),
+    TimesRetired(Dwarf),
+    SeasonXp(Guid),
+    Scrip(Guid),
+}
+
+impl Target {
+    fn parse(path: &str) -> Option<Self> {
+        if let Some((_, resource)) = RESOURCES.iter().find(|(p, _)| *p == path) {
+            return Some(Self::Resource(*resource));
+        }
+
+        match path.split('.').collect::<Vec<_>>()[..] {
+            ["dwarfs", dwarf, field] => {
+                let dwarf = Dwarf::ALL.into_iter().find(|d| dwarf_name(*d) == dwarf)?;
+                match field {
+                    "xp" => Some(Self::Xp(dwarf)),
+                    "times_retired" => Some(Self::TimesRetired(dwarf)),
+                    _ => None,
+                }
+            }
+            ["seasons", season, field] => {
+                let season = season.parse().ok()?;
+                match field {
+                    "xp" => Some(Self::SeasonXp(season)),
+                    "scrip" => Some(Self::Scrip(season)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn ty(self) -> ValueType {
+        match self {
+            Self::Resource(Resource::Credits | Resource::PerkPoints) => ValueType::Int,
+            Self::Resource(_) => ValueType::Float,
+            _ => ValueType::Int,
+        }
+    }
+
+    fn bounds(self) -> (f64, f64) {
+        match self {
+            Self::Resource(resource) => (0.0, resource.max()),
+            Self::Xp(_) => (0.0, XP_TABLE[XP_TABLE.len() - 1] as f64),
+            _ => (0.0, MAX_I32 as f64),
+        }
+    }
+}
+
+impl Value {
+    pub fn ty(self) -> ValueType {
+        match self {
+            Self::Int(_) => ValueType::Int,
+            Self::Float(_) => ValueType::Float,
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Self::Int(v) => v as f64,
+            Self::Float(v) => v as f64,
+        }
+    }
+}
+
+impl SaveFile {
+    pub fn paths(&self) -> Vec<Field> {
+        let dwarfs = Dwarf::ALL.into_iter().flat_map(|d| {
+            let name = dwarf_name(d);
+            [
+                (format!("dwarfs.{name}.xp"), Target::Xp(d)),
+                (
+                    format!("dwarfs.{name}.times_retired"),
+                    Target::TimesRetired(d),
+                ),
+            ]
+        });
+        let seasons = self.seasons.iter().flat_map(|s| {
+            [
+                (
+                    format!("seasons.{}.xp", s.guid()),
+                    Target::SeasonXp(s.guid()),
+                ),
+                (
+                    format!("seasons.{}.scrip", s.guid()),
+                    Target::Scrip(s.guid()),
+                ),
+            ]
+        });
+
+        RESOURCES
+            .iter()
+            .map(|(p, r)| (p.to_string(), Target::Resource(*r)))
+            .chain(dwarfs)
+            .chain(seasons)
+            .map(|(path, target)| {
+                let (min, max) = target.bounds();
+                Field {
+                    path,
+                    ty: target.ty(),
+                    min,
+                    max,
+                }
+            })
+            .collect()
+    }
+
+    pub fn get_path(&self, path: &str) -> Result<Value, FieldError> {
+        let unknown = || FieldError::UnknownPath(path.to_string());
+        let target = Target::parse(path).ok_or_else(unknown)?;
+
+        Ok(match target {
+            Target::Resource(resource) => match target.ty() {
+                ValueType::Int => Value::Int(self.resource(resource) as i32),
+                ValueType::Float => Value::Float(self.resource(resource) as f32),
+            },
+            Target::Xp(dwarf) => Value::Int(self.rank(dwarf).xp),
+            Target::TimesRetired(dwarf) => Value::Int(self.rank(dwarf).times_retired),
+            Target::SeasonXp(guid) => Value::Int(self.seasons.get(guid).ok_or_else(unknown)?.xp),
+            Target::Scrip(guid) => Value::Int(self.seasons.get(guid).ok_or_else(unknown)?.scrip),
+        })
+    }
+
+    pub fn set_path(&mut self, path: &str, value: Value) -> Result<(), FieldError> {
+        let unknown = || FieldError::UnknownPath(path.to_string());
+        let target = Target::parse(path).ok_or_else(unknown)?;
+
+        if value.ty() != target.ty() {
+            return Err(FieldError::TypeMismatch {
+                path: path.to_string(),
+                expected: target.ty(),
+                found: value.ty(),
+            });
+        }
+
+        let (min, max) = target.bounds();
+        let amount = value.as_f64();
+        let out_of_bounds = || FieldError::OutOfBounds {
+            path: path.to_string(),
+            value: amount,
+            min,
+            max,
+        };
+        if !(min..=max).contains(&amount) {
+            return Err(out_of_bounds());
+        }
+
+        match target {
+            Target::Resource(resource) => self
+                .set_resource(resource, amount)
+                .map_err(|_| out_of_bounds())?,
+            Target::Xp(dwarf) => {
+                let rank = self.rank_mut(dwarf);
+                *rank = Rank::new(amount as i32, rank.times_retired);
+            }
+            Target::TimesRetired(dwarf) => {
+                let rank = self.rank_mut(dwarf);
+                *rank = Rank::new(rank.xp, amount as i32);
+            }
+            Target::SeasonXp(guid) => {
+                self.seasons.get_mut(guid).ok_or_else(unknown)?.xp = amount as i32
+            }
+            Target::Scrip(guid) => {
+                self.seasons.get_mut(guid).ok_or_else(unknown)?.scrip = amount as i32
+            }
+        }
+
+        Ok(())
+    }
+
+    fn rank(&self, dwarf: Dwarf) -> &Rank {
+        let dwarfs = &self.dwarfs;
+        match dwarf {
+            Dwarf::Engineer => &dwarfs.engineer,
+            Dwarf::Driller => &dwarfs.driller,
+            Dwarf::Gunner => &dwarfs.gunner,
+            Dwarf::Scout => &dwarfs.scout,
+        }
+    }
+
+    fn rank_mut(&mut self, dwarf: Dwarf) -> &mut Rank {
+        let dwarfs = &mut self.dwarfs;
+        match dwarf {
+            Dwarf::Engineer => &mut dwarfs.engineer,
+            Dwarf::Driller => &mut dwarfs.driller,
+            Dwarf::Gunner => &mut dwarfs.gunner,
+            Dwarf::Scout => &mut dwarfs.scout,
+        }
+    }
+}
+
+// The `dwarfs.<name>` segment of a path, the field name in `Dwarfs`.
+fn dwarf_name(dwarf: Dwarf) -> &'static str {
+    match dwarf {
+        Dwarf::Engineer => "engineer",
+        Dwarf::Driller => "driller",
+        Dwarf::Gunner => "gunner",
+        Dwarf::Scout => "scout",
+    }
+}
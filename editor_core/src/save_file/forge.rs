@@ -1,86 +1,137 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use gvas::{
-    properties::{array_property::ArrayProperty, Property},
+    properties::{
+        array_property::ArrayProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        Property,
+    },
+    types::Guid,
     GvasFile,
 };
 
 use crate::{
+    cast, custom_struct, custom_struct_mut,
     error::{Error, ParsingError},
-    get,
+    get_field, get_field_mut, get_guid,
     registry::{Schematic, Status, SCHEMATICS},
 };
 
+use super::section::SaveSection;
+
 #[derive(Debug, Default, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Forge {
     pub owned_schematics: HashMap<[u8; 16], Schematic>,
     pub forged_schematics: HashMap<[u8; 16], Schematic>,
+    // Schematics that aren't in the registry, they're written back as is.
+    pub unknown: Vec<Guid>,
 }
 
 impl Forge {
-    pub(crate) fn from_gvas(gvas: &GvasFile) -> Result<Self, Error> {
-        Ok(Self {
-            owned_schematics: Self::get_owned(gvas)?,
-            forged_schematics: Self::get_forged(gvas)?,
-        })
+    pub fn grant(&mut self, guid: Guid) -> bool {
+        if self.owned_schematics.contains_key(&guid.0)
+            || self.forged_schematics.contains_key(&guid.0)
+        {
+            return false;
+        }
+
+        match SCHEMATICS.get(&guid.0) {
+            Some(c) => {
+                let mut schematic = c.to_owned();
+                schematic.set_status(Some(Status::Unforged));
+
+                self.owned_schematics.insert(guid.0, schematic);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn forge(&mut self, guid: Guid) -> bool {
+        match self.owned_schematics.remove(&guid.0) {
+            Some(mut schematic) => {
+                schematic.set_status(Some(Status::Forged));
+
+                self.forged_schematics.insert(guid.0, schematic);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn unforge(&mut self, guid: Guid) -> bool {
+        match self.forged_schematics.remove(&guid.0) {
+            Some(mut schematic) => {
+                schematic.set_status(Some(Status::Unforged));
+
+                self.owned_schematics.insert(guid.0, schematic);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn revoke(&mut self, guid: Guid) -> bool {
+        let owned = self.owned_schematics.remove(&guid.0).is_some();
+        let forged = self.forged_schematics.remove(&guid.0).is_some();
+
+        owned || forged
     }
 
     fn get_schematic_save(gvas: &GvasFile) -> Result<&Vec<(String, Property)>, Error> {
         let properties = &gvas.properties;
-        let property = get!(properties, "SchematicSave", StructProperty)?;
-        let schematic_save = property
-            .value
-            .get_custom_struct()
-            .ok_or_else(|| ParsingError::failed_cast("CustomStruct"))?;
+        let property = properties
+            .get("SchematicSave")
+            .ok_or_else(|| ParsingError::missing_entry("SchematicSave"))?;
 
-        Ok(schematic_save.1)
+        custom_struct(property, "SchematicSave")
     }
 
-    fn get_owned(gvas: &GvasFile) -> Result<HashMap<[u8; 16], Schematic>, Error> {
+    fn get_owned(
+        gvas: &GvasFile,
+        unknown: &mut Vec<Guid>,
+    ) -> Result<HashMap<[u8; 16], Schematic>, Error> {
         let schematic_save = Self::get_schematic_save(gvas)?;
         let properties = &get_array(schematic_save, "OwnedSchematics".to_owned())?.properties;
 
         let mut schematics = HashMap::new();
-        for property in properties {
-            let property = property
-                .get_struct()
-                .ok_or_else(|| ParsingError::failed_cast("StructProperty"))?;
-            let schematic = property
-                .value
-                .get_guid()
-                .ok_or_else(|| ParsingError::failed_cast("Guid"))?;
-
-            if let Some(c) = SCHEMATICS.get(&schematic.0) {
-                let mut schematic = c.to_owned();
-                schematic.set_status(Some(Status::Unforged));
+        for (i, property) in properties.iter().enumerate() {
+            let schematic = get_guid(property, &format!("SchematicSave.OwnedSchematics[{i}]"))?;
+
+            match SCHEMATICS.get(&schematic.0) {
+                Some(c) => {
+                    let mut schematic = c.to_owned();
+                    schematic.set_status(Some(Status::Unforged));
 
-                schematics.insert(c.get_guid().0, schematic);
+                    schematics.insert(c.get_guid().0, schematic);
+                }
+                None => unknown.push(schematic),
             }
         }
 
         Ok(schematics)
     }
 
-    fn get_forged(gvas: &GvasFile) -> Result<HashMap<[u8; 16], Schematic>, Error> {
+    fn get_forged(
+        gvas: &GvasFile,
+        unknown: &mut Vec<Guid>,
+    ) -> Result<HashMap<[u8; 16], Schematic>, Error> {
         let schematic_save = Self::get_schematic_save(gvas)?;
         let properties = &get_array(schematic_save, "ForgedSchematics".to_owned())?.properties;
 
         let mut schematics = HashMap::new();
-        for property in properties {
-            let property = property
-                .get_struct()
-                .ok_or_else(|| ParsingError::failed_cast("StructProperty"))?;
-            let schematic = property
-                .value
-                .get_guid()
-                .ok_or_else(|| ParsingError::failed_cast("Guid"))?;
-
-            if let Some(c) = SCHEMATICS.get(&schematic.0) {
-                let mut schematic = c.to_owned();
-                schematic.set_status(Some(Status::Forged));
+        for (i, property) in properties.iter().enumerate() {
+            let schematic = get_guid(property, &format!("SchematicSave.ForgedSchematics[{i}]"))?;
+
+            match SCHEMATICS.get(&schematic.0) {
+                Some(c) => {
+                    let mut schematic = c.to_owned();
+                    schematic.set_status(Some(Status::Forged));
 
-                schematics.insert(c.get_guid().0, schematic);
+                    schematics.insert(c.get_guid().0, schematic);
+                }
+                None => unknown.push(schematic),
             }
         }
 
@@ -88,13 +139,108 @@ impl Forge {
     }
 }
 
-fn get_array(schematic_save: &[(String, Property)], prop: String) -> Result<&ArrayProperty, Error> {
-    Ok(schematic_save
-        .iter()
-        .find_map(|p| match p.0 == prop {
-            true => Some(p.1.get_array()),
-            false => None,
+impl SaveSection for Forge {
+    fn name() -> &'static str {
+        "forge"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let mut unknown = Vec::new();
+
+        Ok(Self {
+            owned_schematics: Self::get_owned(gvas, &mut unknown)?,
+            forged_schematics: Self::get_forged(gvas, &mut unknown)?,
+            unknown,
         })
-        .ok_or_else(|| ParsingError::missing_entry(&prop))?
-        .ok_or_else(|| ParsingError::failed_cast("ArrayProperty"))?)
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let properties = &mut gvas.properties;
+        let property = properties
+            .get_mut("SchematicSave")
+            .ok_or_else(|| ParsingError::missing_entry("SchematicSave"))?;
+        let schematic_save = custom_struct_mut(property, "SchematicSave")?;
+
+        let owned = get_array_mut(schematic_save, "OwnedSchematics".to_owned())?;
+        write_schematics(owned, &self.owned_schematics);
+
+        let forged = get_array_mut(schematic_save, "ForgedSchematics".to_owned())?;
+        write_schematics(forged, &self.forged_schematics);
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        for (name, schematics) in [
+            ("OwnedSchematics", &self.owned_schematics),
+            ("ForgedSchematics", &self.forged_schematics),
+        ] {
+            for guid in schematics.keys() {
+                let path = format!("SchematicSave.{name}{{{}}}", Guid(*guid));
+                if !SCHEMATICS.contains_key(guid) {
+                    return Err(ParsingError::invalid_value(&path, "unknown schematic").into());
+                }
+            }
+        }
+
+        if let Some(guid) = self
+            .forged_schematics
+            .keys()
+            .find(|g| self.owned_schematics.contains_key(*g))
+        {
+            let path = format!("SchematicSave.ForgedSchematics{{{}}}", Guid(*guid));
+            return Err(ParsingError::invalid_value(&path, "schematic is also owned").into());
+        }
+
+        Ok(())
+    }
+}
+
+fn get_array(schematic_save: &[(String, Property)], prop: String) -> Result<&ArrayProperty, Error> {
+    let property = get_field(schematic_save, "SchematicSave", &prop)?;
+    Ok(cast!(
+        property,
+        ArrayProperty,
+        format!("SchematicSave.{prop}")
+    )?)
+}
+
+fn get_array_mut(
+    schematic_save: &mut [(String, Property)],
+    prop: String,
+) -> Result<&mut ArrayProperty, Error> {
+    let property = get_field_mut(schematic_save, "SchematicSave", &prop)?;
+    Ok(cast!(
+        property,
+        ArrayProperty,
+        format!("SchematicSave.{prop}")
+    )?)
+}
+
+// Keeps the existing order and any GUIDs the registry doesn't know about,
+// drops removed schematics and appends new ones.
+fn write_schematics(array: &mut ArrayProperty, schematics: &HashMap<[u8; 16], Schematic>) {
+    let mut present = HashSet::new();
+    array
+        .properties
+        .retain(|p| match p.get_struct().and_then(|s| s.value.get_guid()) {
+            Some(guid) if SCHEMATICS.contains_key(&guid.0) => {
+                present.insert(guid.0);
+                schematics.contains_key(&guid.0)
+            }
+            _ => true,
+        });
+
+    let mut added = schematics
+        .keys()
+        .filter(|guid| !present.contains(*guid))
+        .collect::<Vec<_>>();
+    added.sort();
+
+    array.properties.extend(added.into_iter().map(|guid| {
+        Property::from(StructProperty::new(
+            Guid::from(0),
+            StructPropertyValue::Guid(Guid(*guid)),
+        ))
+    }));
 }
@@ -0,0 +1,242 @@
+use gvas::{
+    properties::{
+        array_property::ArrayProperty,
+        map_property::MapProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        Property,
+    },
+    types::Guid,
+    GvasFile,
+};
+use indexmap::IndexMap;
+
+use crate::{
+    cast, custom_struct, custom_struct_mut,
+    error::{Error, ParsingError},
+    find_field, get_field, get_field_mut, get_guid, get_guids, guid_property,
+    registry::Dwarf,
+    set_field,
+};
+
+use super::{section::SaveSection, upgrades::UpgradeSelection};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Loadout {
+    // SavegameID of the dwarf the slot belongs to, see `Dwarf::from_guid`.
+    pub character: Option<Guid>,
+    pub items: Vec<UpgradeSelection>,
+    // Equipped armor, headwear, beards and other vanity items.
+    pub cosmetics: Vec<Guid>,
+}
+
+// Slots can be added and removed by editing `slots`, the save is resized to
+// match on write.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Loadouts {
+    pub slots: Vec<Loadout>,
+}
+
+impl Loadout {
+    pub fn dwarf(&self) -> Option<Dwarf> {
+        self.character.and_then(Dwarf::from_guid)
+    }
+
+    pub fn get(&self, item: Guid) -> Option<&UpgradeSelection> {
+        self.items.iter().find(|s| s.item == item)
+    }
+
+    pub fn get_mut(&mut self, item: Guid) -> Option<&mut UpgradeSelection> {
+        self.items.iter_mut().find(|s| s.item == item)
+    }
+
+    // Slots from older saves don't name their dwarf and match any other.
+    fn same_dwarf(&self, other: &Loadout) -> bool {
+        match (self.character, other.character) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        }
+    }
+
+    fn take_from(&mut self, other: &Loadout) {
+        self.items = other.items.clone();
+        self.cosmetics = other.cosmetics.clone();
+    }
+
+    fn from_property(property: &Property, path: &str) -> Result<Self, Error> {
+        let fields = custom_struct(property, path)?;
+
+        let loadout_path = format!("{path}.Loadout");
+        let loadout = cast!(
+            get_field(fields, path, "Loadout")?,
+            MapProperty,
+            loadout_path
+        )?;
+        let items = loadout
+            .value
+            .iter()
+            .enumerate()
+            .map(|(i, (k, v))| {
+                let item = get_guid(k, &format!("{loadout_path}[{i}]"))?;
+                UpgradeSelection::from_property(item, v, &format!("{loadout_path}{{{item}}}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        // Older saves have neither field.
+        let character = find_field(fields, "CharacterID")
+            .map(|p| get_guid(p, &format!("{path}.CharacterID")))
+            .transpose()?;
+        let cosmetics = match find_field(fields, "VanityItems") {
+            Some(p) => {
+                let cosmetics_path = format!("{path}.VanityItems");
+                let cosmetics = cast!(p, ArrayProperty, cosmetics_path)?;
+                get_guids(&cosmetics.properties, &cosmetics_path)?
+            }
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            character,
+            items,
+            cosmetics,
+        })
+    }
+
+    fn write(&self, property: &mut Property, path: &str) -> Result<(), Error> {
+        let fields = custom_struct_mut(property, path)?;
+
+        let loadout_path = format!("{path}.Loadout");
+        let loadout = &mut cast!(
+            get_field_mut(fields, path, "Loadout")?,
+            MapProperty,
+            loadout_path
+        )?
+        .value;
+        loadout.retain(|k, _| get_guid(k, "").map_or(true, |g| self.get(g).is_some()));
+        for item in &self.items {
+            match loadout.get_mut(&guid_property(item.item)) {
+                Some(p) => item.write(p, &format!("{loadout_path}{{{}}}", item.item))?,
+                None => {
+                    loadout.insert(guid_property(item.item), item.to_property());
+                }
+            }
+        }
+
+        set_field(fields, "CharacterID", self.character.map(guid_property));
+        // An empty list is only written if the save had one.
+        let cosmetics = (!self.cosmetics.is_empty() || find_field(fields, "VanityItems").is_some())
+            .then(|| guid_array("VanityItems", &self.cosmetics));
+        set_field(fields, "VanityItems", cosmetics);
+
+        Ok(())
+    }
+}
+
+impl Loadouts {
+    // Copies the items and cosmetics of slot `from` into slot `to`, which
+    // keeps its dwarf. Fails for slots of two different dwarfs.
+    pub fn copy(&mut self, from: usize, to: usize) -> bool {
+        match self.slots.get(from).cloned() {
+            Some(loadout) => self.import(to, &loadout),
+            None => false,
+        }
+    }
+
+    // Same as `copy` with a loadout from another save.
+    pub fn import(&mut self, slot: usize, loadout: &Loadout) -> bool {
+        match self.slots.get_mut(slot) {
+            Some(s) if s.same_dwarf(loadout) => {
+                s.take_from(loadout);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Slot indices and loadouts of `dwarf`.
+    pub fn of_dwarf(&self, dwarf: Dwarf) -> impl Iterator<Item = (usize, &Loadout)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(move |(_, l)| l.dwarf() == Some(dwarf))
+    }
+}
+
+impl SaveSection for Loadouts {
+    fn name() -> &'static str {
+        "loadouts"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let props = &gvas.properties;
+        let loadouts = match props.get("ItemUpgradeLoadouts") {
+            Some(p) => &cast!(p, ArrayProperty, "ItemUpgradeLoadouts")?.properties,
+            None => return Ok(Self::default()),
+        };
+
+        let slots = loadouts
+            .iter()
+            .enumerate()
+            .map(|(i, l)| Loadout::from_property(l, &format!("ItemUpgradeLoadouts[{i}]")))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { slots })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let props = &mut gvas.properties;
+        if self.slots.is_empty() && !props.contains_key("ItemUpgradeLoadouts") {
+            return Ok(());
+        }
+
+        let loadouts = props
+            .entry("ItemUpgradeLoadouts".to_string())
+            .or_insert_with(|| {
+                Property::from(ArrayProperty::new(
+                    "StructProperty".to_string(),
+                    Some((
+                        "ItemUpgradeLoadouts".to_string(),
+                        "ItemUpgradeLoadout".to_string(),
+                        Guid::from(0),
+                    )),
+                    Vec::new(),
+                ))
+            });
+        let loadouts = &mut cast!(loadouts, ArrayProperty, "ItemUpgradeLoadouts")?.properties;
+
+        loadouts.truncate(self.slots.len());
+        loadouts.resize_with(self.slots.len(), empty_loadout);
+        for (i, (loadout, slot)) in loadouts.iter_mut().zip(&self.slots).enumerate() {
+            slot.write(loadout, &format!("ItemUpgradeLoadouts[{i}]"))?;
+        }
+
+        Ok(())
+    }
+}
+
+// An ItemUpgradeLoadout for slots added since the save was read.
+fn empty_loadout() -> Property {
+    let loadout = MapProperty::new(
+        "StructProperty".to_string(),
+        "StructProperty".to_string(),
+        0,
+        IndexMap::new(),
+    );
+
+    Property::from(StructProperty::new(
+        Guid::from(0),
+        StructPropertyValue::CustomStruct(
+            "ItemUpgradeLoadout".to_string(),
+            vec![("Loadout".to_string(), Property::from(loadout))],
+        ),
+    ))
+}
+
+fn guid_array(name: &str, guids: &[Guid]) -> Property {
+    Property::from(ArrayProperty::new(
+        "StructProperty".to_string(),
+        Some((name.to_string(), "Guid".to_string(), Guid::from(0))),
+        guids.iter().copied().map(guid_property).collect(),
+    ))
+}
@@ -0,0 +1,56 @@
+use gvas::{types::Guid, GvasFile};
+
+use crate::{error::Error, get_guid_set, get_guids, write_guid_set};
+
+use super::section::SaveSection;
+
+// There's no list of the manual's objects to mark all of them discovered,
+// or to name them. `discover_all` discovers the objects it's given.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinersManual {
+    pub known_objects: Vec<Guid>,
+}
+
+impl MinersManual {
+    pub fn is_discovered(&self, object: Guid) -> bool {
+        self.known_objects.contains(&object)
+    }
+
+    pub fn discover(&mut self, object: Guid) -> bool {
+        if self.is_discovered(object) {
+            return false;
+        }
+
+        self.known_objects.push(object);
+        true
+    }
+
+    // Returns how many of `objects` weren't discovered yet.
+    pub fn discover_all(&mut self, objects: impl IntoIterator<Item = Guid>) -> usize {
+        objects.into_iter().filter(|o| self.discover(*o)).count()
+    }
+
+    pub fn reset(&mut self) {
+        self.known_objects.clear();
+    }
+}
+
+impl SaveSection for MinersManual {
+    fn name() -> &'static str {
+        "miners_manual"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let known_objects = match get_guid_set(gvas, "MinersManualKnownObjects", None)? {
+            Some(set) => get_guids(set, "MinersManualKnownObjects")?,
+            None => Vec::new(),
+        };
+
+        Ok(Self { known_objects })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        write_guid_set(gvas, "MinersManualKnownObjects", None, &self.known_objects)
+    }
+}
@@ -1,12 +1,14 @@
 use gvas::GvasFile;
 
 use crate::{
-    error::{Error, ParsingError},
-    get, get_owned_resources,
+    error::Error,
+    resources::{check_amount, OwnedResources},
 };
 
 use crate::registry::{BISMOR, CROPPA, ENOR_PEARL, JADIZ, MAGNITE, UMANITE};
 
+use super::section::SaveSection;
+
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Minerals {
@@ -18,26 +20,20 @@ pub struct Minerals {
     pub enor_pearl: f32,
 }
 
-// #[cfg(feature = "field_access")]
-pub enum Mineral {
-    Magnite,
-    Bismor,
-    Croppa,
-    Umanite,
-    Jadiz,
-    EnorPearl,
-}
+impl SaveSection for Minerals {
+    fn name() -> &'static str {
+        "minerals"
+    }
 
-impl Minerals {
-    pub(crate) fn from_gvas(gvas: &GvasFile) -> Result<Self, Error> {
-        let owned_resources = get_owned_resources(gvas)?;
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let owned_resources = OwnedResources::new(gvas)?;
 
-        let magnite = get!(owned_resources, &MAGNITE)?.value.0;
-        let bismor = get!(owned_resources, &BISMOR)?.value.0;
-        let croppa = get!(owned_resources, &CROPPA)?.value.0;
-        let umanite = get!(owned_resources, &UMANITE)?.value.0;
-        let jadiz = get!(owned_resources, &JADIZ)?.value.0;
-        let enor_pearl = get!(owned_resources, &ENOR_PEARL)?.value.0;
+        let magnite = owned_resources.get(MAGNITE).unwrap_or_default();
+        let bismor = owned_resources.get(BISMOR).unwrap_or_default();
+        let croppa = owned_resources.get(CROPPA).unwrap_or_default();
+        let umanite = owned_resources.get(UMANITE).unwrap_or_default();
+        let jadiz = owned_resources.get(JADIZ).unwrap_or_default();
+        let enor_pearl = owned_resources.get(ENOR_PEARL).unwrap_or_default();
 
         Ok(Self {
             magnite,
@@ -49,26 +45,30 @@ impl Minerals {
         })
     }
 
-    // #[cfg(feature = "field_access")]
-    pub fn get(&self, field: Mineral) -> f32 {
-        match field {
-            Mineral::Magnite => self.magnite,
-            Mineral::Bismor => self.bismor,
-            Mineral::Croppa => self.croppa,
-            Mineral::Umanite => self.umanite,
-            Mineral::Jadiz => self.jadiz,
-            Mineral::EnorPearl => self.enor_pearl,
-        }
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let mut resources = OwnedResources::new_mut(gvas)?;
+        resources.set_amount(MAGNITE, self.magnite);
+        resources.set_amount(BISMOR, self.bismor);
+        resources.set_amount(CROPPA, self.croppa);
+        resources.set_amount(UMANITE, self.umanite);
+        resources.set_amount(JADIZ, self.jadiz);
+        resources.set_amount(ENOR_PEARL, self.enor_pearl);
+
+        Ok(())
     }
 
-    pub fn set(&mut self, field: Mineral, value: f32) {
-        match field {
-            Mineral::Magnite => self.magnite = value,
-            Mineral::Bismor => self.bismor = value,
-            Mineral::Croppa => self.croppa = value,
-            Mineral::Umanite => self.umanite = value,
-            Mineral::Jadiz => self.jadiz = value,
-            Mineral::EnorPearl => self.enor_pearl = value,
+    fn validate(&self) -> Result<(), Error> {
+        for (guid, amount) in [
+            (MAGNITE, self.magnite),
+            (BISMOR, self.bismor),
+            (CROPPA, self.croppa),
+            (UMANITE, self.umanite),
+            (JADIZ, self.jadiz),
+            (ENOR_PEARL, self.enor_pearl),
+        ] {
+            check_amount(guid, amount)?;
         }
+
+        Ok(())
     }
 }
@@ -1,11 +1,17 @@
-use gvas::{properties::Property, GvasFile};
+use gvas::{
+    properties::{int_property::IntProperty, Property},
+    GvasFile,
+};
 
 use crate::{
     error::{Error, ParsingError},
-    get, get_owned_resources,
+    get, get_mut,
+    resources::{check_amount, OwnedResources},
 };
 
-use crate::registry::{BLANK_CORES, DATA_CELLS, ERROR_CUBES, PHAZYONITE};
+use crate::registry::{BLANK_CORES, DATA_CELLS, ERROR_CUBES, MAX_I32, PHAZYONITE};
+
+use super::section::SaveSection;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -18,18 +24,22 @@ pub struct Miscellaneous {
     pub phazyonite: f32,
 }
 
-impl Miscellaneous {
-    pub(crate) fn from_gvas(gvas: &GvasFile) -> Result<Self, Error> {
+impl SaveSection for Miscellaneous {
+    fn name() -> &'static str {
+        "miscellaneous"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
         let props = &gvas.properties;
-        let owned_resources = get_owned_resources(gvas)?;
+        let owned_resources = OwnedResources::new(gvas)?;
 
         let credits = get!(props, "Credits", IntProperty)?.value;
         let perk_points = get!(props, "PerkPoints", IntProperty)?.value;
 
-        let error_cubes = get!(owned_resources, &ERROR_CUBES)?.value.0;
-        let data_cells = get!(owned_resources, &DATA_CELLS)?.value.0;
-        let blank_cores = get!(owned_resources, &BLANK_CORES)?.value.0;
-        let phazyonite = get!(owned_resources, &PHAZYONITE)?.value.0;
+        let error_cubes = owned_resources.get(ERROR_CUBES).unwrap_or_default();
+        let data_cells = owned_resources.get(DATA_CELLS).unwrap_or_default();
+        let blank_cores = owned_resources.get(BLANK_CORES).unwrap_or_default();
+        let phazyonite = owned_resources.get(PHAZYONITE).unwrap_or_default();
 
         Ok(Self {
             credits,
@@ -40,4 +50,38 @@ impl Miscellaneous {
             phazyonite,
         })
     }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let mut resources = OwnedResources::new_mut(gvas)?;
+        resources.set_amount(ERROR_CUBES, self.error_cubes);
+        resources.set_amount(DATA_CELLS, self.data_cells);
+        resources.set_amount(BLANK_CORES, self.blank_cores);
+        resources.set_amount(PHAZYONITE, self.phazyonite);
+
+        let props = &mut gvas.properties;
+        *get_mut!(props, "Credits", IntProperty)? = IntProperty::new(self.credits);
+        *get_mut!(props, "PerkPoints", IntProperty)? = IntProperty::new(self.perk_points);
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        for (path, amount) in [("Credits", self.credits), ("PerkPoints", self.perk_points)] {
+            if !(0..=MAX_I32).contains(&amount) {
+                let reason = format!("{amount} is not in 0..={MAX_I32}");
+                return Err(ParsingError::invalid_value(path, &reason).into());
+            }
+        }
+
+        for (guid, amount) in [
+            (ERROR_CUBES, self.error_cubes),
+            (DATA_CELLS, self.data_cells),
+            (BLANK_CORES, self.blank_cores),
+            (PHAZYONITE, self.phazyonite),
+        ] {
+            check_amount(guid, amount)?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,71 @@
+use gvas::{properties::Property, types::Guid, GvasFile};
+
+use crate::{
+    cast,
+    error::{Error, ParsingError},
+    get_guids, get_mut, write_guid_map,
+};
+
+use super::section::SaveSection;
+
+// Parameters are handled by GUID, no generated table names them.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MissionParameters {
+    pub unlocked: Vec<Guid>,
+}
+
+impl MissionParameters {
+    pub fn is_unlocked(&self, parameter: Guid) -> bool {
+        self.unlocked.contains(&parameter)
+    }
+
+    pub fn set_unlocked(&mut self, parameter: Guid, unlocked: bool) {
+        match (self.is_unlocked(parameter), unlocked) {
+            (false, true) => self.unlocked.push(parameter),
+            (true, false) => self.unlocked.retain(|p| *p != parameter),
+            _ => (),
+        }
+    }
+
+    pub fn toggle(&mut self, parameter: Guid) {
+        self.set_unlocked(parameter, !self.is_unlocked(parameter));
+    }
+}
+
+impl SaveSection for MissionParameters {
+    fn name() -> &'static str {
+        "mission_parameters"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let props = &gvas.properties;
+        let unlocked = match props.get("UnLockedMissionParameters") {
+            Some(p) => &cast!(p, MapProperty, "UnLockedMissionParameters")?.value,
+            None => return Ok(Self::default()),
+        };
+
+        Ok(Self {
+            unlocked: get_guids(unlocked.keys(), "UnLockedMissionParameters")?,
+        })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let props = &mut gvas.properties;
+        if self.unlocked.is_empty() && !props.contains_key("UnLockedMissionParameters") {
+            return Ok(());
+        }
+
+        let unlocked = &mut get_mut!(props, "UnLockedMissionParameters", MapProperty)?.value;
+
+        // The layout of the values isn't known, newly unlocked parameters get
+        // a copy of an existing entry.
+        let template = unlocked.values().next().cloned();
+        write_guid_map(unlocked, &self.unlocked, || {
+            let reason = "no unlocked parameter to copy the entry of";
+            Ok(template
+                .clone()
+                .ok_or_else(|| ParsingError::invalid_value("UnLockedMissionParameters", reason))?)
+        })
+    }
+}
@@ -1,35 +1,57 @@
 pub mod brewing;
+pub mod dlc;
+pub mod drinks;
 pub mod dwarfs;
+pub mod enemies;
+pub mod events;
+#[cfg(feature = "field_access")]
+pub mod field_access;
 pub mod forge;
+pub mod loadouts;
+pub mod manual;
 pub mod minerals;
 pub mod miscellaneous;
+pub mod missions;
+pub mod pickaxe;
+pub mod seasons;
+pub mod section;
+pub mod skins;
+pub mod upgrades;
 
+use std::fmt;
 #[cfg(not(feature = "wasm"))]
-use std::fs::File;
-use std::{io::Cursor, path::Path};
-
-use gvas::{
-    properties::{
-        int_property::{FloatProperty, IntProperty},
-        struct_property::{StructProperty, StructPropertyValue},
-        Property,
-    },
-    types::Guid,
-    GvasFile,
-};
+use std::path::Path;
+
+#[cfg(not(feature = "wasm"))]
+use crate::{read_gvas, write_gvas};
+
+use gvas::GvasFile;
 
 use crate::{
-    error::{Error, ParsingError},
-    get_mut, get_resource_mut,
-    registry::{
-        get_hints, BARLEY_BULB, BISMOR, BLANK_CORES, CROPPA, DATA_CELLS, ENOR_PEARL, ERROR_CUBES,
-        JADIZ, MAGNITE, MALT_STAR, PHAZYONITE, STARCH_NUT, UMANITE, YEAST_CONE,
-    },
+    error::{Diagnostic, Error, ParsingError},
+    gvas_bytes, read_gvas_bytes,
+    registry::Resource,
+    resources::OwnedResources,
 };
 
 use self::{
-    brewing::Brewing, dwarfs::Characters, forge::Forge, minerals::Minerals,
+    brewing::Brewing,
+    dlc::Dlc,
+    drinks::Drinks,
+    dwarfs::Characters,
+    enemies::EnemyStats,
+    events::EventRewards,
+    forge::Forge,
+    loadouts::Loadouts,
+    manual::MinersManual,
+    minerals::Minerals,
     miscellaneous::Miscellaneous,
+    missions::MissionParameters,
+    pickaxe::PickaxeParts,
+    seasons::Seasons,
+    section::{DynSection, RegisteredSections, SaveSection},
+    skins::Skins,
+    upgrades::Upgrades,
 };
 
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -40,89 +62,286 @@ pub struct SaveFile {
     pub miscellaneous: Miscellaneous,
     pub dwarfs: Characters,
     pub forge: Forge,
+    pub seasons: Seasons,
+    pub upgrades: Upgrades,
+    pub loadouts: Loadouts,
+    pub enemy_stats: EnemyStats,
+    pub skins: Skins,
+    pub drinks: Drinks,
+    pub pickaxe_parts: PickaxeParts,
+    pub miners_manual: MinersManual,
+    pub event_rewards: EventRewards,
+    pub dlc: Dlc,
+    pub mission_parameters: MissionParameters,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) registered: RegisteredSections,
 }
 
 impl SaveFile {
     #[cfg(not(feature = "wasm"))]
     pub fn from_path(path: &Path) -> Result<Self, Error> {
-        let mut file = File::open(path)?;
-        let gvas = GvasFile::read_with_hints(&mut file, &get_hints())?;
+        let gvas = read_gvas(path)?;
 
         Self::from_gvas(&gvas)
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
-        let mut cursor = Cursor::new(bytes);
-        let gvas = GvasFile::read_with_hints(&mut cursor, &get_hints())?;
+        let gvas = read_gvas_bytes(bytes)?;
 
         Self::from_gvas(&gvas)
     }
 
-    fn from_gvas(gvas: &GvasFile) -> Result<Self, Error> {
+    pub fn from_gvas(gvas: &GvasFile) -> Result<Self, Error> {
+        Self::read_sections(gvas, Err)
+    }
+
+    // Reads every section it can, sections that fail to parse are left at
+    // their default and reported in the returned diagnostics.
+    pub fn from_gvas_lenient(gvas: &GvasFile) -> Result<(Self, Vec<Diagnostic>), Error> {
+        let mut diagnostics = Vec::new();
+        let save = Self::read_sections(gvas, |e| match e {
+            Error::Parsing(e) => {
+                diagnostics.push(Diagnostic::Skipped(e));
+                Ok(())
+            }
+            e => Err(e),
+        })?;
+
+        // Resources without a `Resource` variant aren't in any section, they're
+        // reported under the catch-all one. A broken Resources property was
+        // already reported above.
+        let resources = OwnedResources::new(gvas)
+            .map(|r| {
+                r.iter()
+                    .map(|(guid, _)| guid)
+                    .filter(|guid| Resource::from_guid(*guid).is_none())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let unknown = [
+            ("dwarfs", "CharacterSaves", &save.dwarfs.unknown),
+            ("forge", "SchematicSave", &save.forge.unknown),
+            ("miscellaneous", "Resources.OwnedResources", &resources),
+        ];
+        for (section, path, guids) in unknown {
+            diagnostics.extend(guids.iter().map(|guid| Diagnostic::UnknownGuid {
+                section: section.to_string(),
+                path: path.to_string(),
+                guid: *guid,
+            }));
+        }
+
+        Ok((save, diagnostics))
+    }
+
+    fn read_sections(
+        gvas: &GvasFile,
+        mut on_error: impl FnMut(Error) -> Result<(), Error>,
+    ) -> Result<Self, Error> {
+        let on_error = &mut on_error;
+
         Ok(Self {
-            minerals: Minerals::from_gvas(gvas)?,
-            brewing: Brewing::from_gvas(gvas)?,
-            miscellaneous: Miscellaneous::from_gvas(gvas)?,
-            dwarfs: Characters::from_gvas(gvas)?,
-            forge: Forge::from_gvas(gvas)?,
+            minerals: read_section(gvas, on_error)?,
+            brewing: read_section(gvas, on_error)?,
+            miscellaneous: read_section(gvas, on_error)?,
+            dwarfs: read_section(gvas, on_error)?,
+            forge: read_section(gvas, on_error)?,
+            seasons: read_section(gvas, on_error)?,
+            upgrades: read_section(gvas, on_error)?,
+            loadouts: read_section(gvas, on_error)?,
+            enemy_stats: read_section(gvas, on_error)?,
+            skins: read_section(gvas, on_error)?,
+            drinks: read_section(gvas, on_error)?,
+            pickaxe_parts: read_section(gvas, on_error)?,
+            miners_manual: read_section(gvas, on_error)?,
+            event_rewards: read_section(gvas, on_error)?,
+            dlc: read_section(gvas, on_error)?,
+            mission_parameters: read_section(gvas, on_error)?,
+            registered: RegisteredSections::default(),
         })
     }
 
-    pub fn save(&self, gvas: &mut GvasFile, _path: &Path) -> Result<(), Error> {
-        let Self {
-            minerals,
-            brewing,
-            miscellaneous,
-            dwarfs: _,
-            forge: _,
-        } = self;
-
-        let props = &mut gvas.properties;
-        let resources = get_mut!(props, "Resources", StructProperty)?;
-        let resources = &mut resources
-            .value
-            .get_custom_struct_mut()
-            .ok_or_else(|| ParsingError::failed_cast("CustomStruct"))?
-            .1[0]
-            .1
-            .get_map_mut()
-            .ok_or_else(|| ParsingError::failed_cast("MapProperty"))?
-            .value;
-
-        *get_resource_mut!(resources, MAGNITE)? =
-            Property::from(FloatProperty::new(minerals.magnite));
-        *get_resource_mut!(resources, BISMOR)? =
-            Property::from(FloatProperty::new(minerals.bismor));
-        *get_resource_mut!(resources, CROPPA)? =
-            Property::from(FloatProperty::new(minerals.croppa));
-        *get_resource_mut!(resources, UMANITE)? =
-            Property::from(FloatProperty::new(minerals.umanite));
-        *get_resource_mut!(resources, JADIZ)? = Property::from(FloatProperty::new(minerals.jadiz));
-        *get_resource_mut!(resources, ENOR_PEARL)? =
-            Property::from(FloatProperty::new(minerals.enor_pearl));
-
-        *get_resource_mut!(resources, STARCH_NUT)? =
-            Property::from(FloatProperty::new(brewing.starch_nut));
-        *get_resource_mut!(resources, YEAST_CONE)? =
-            Property::from(FloatProperty::new(brewing.yeast_cone));
-        *get_resource_mut!(resources, MALT_STAR)? =
-            Property::from(FloatProperty::new(brewing.malt_star));
-        *get_resource_mut!(resources, BARLEY_BULB)? =
-            Property::from(FloatProperty::new(brewing.barley_bulb));
-
-        *get_resource_mut!(resources, ERROR_CUBES)? =
-            Property::from(FloatProperty::new(miscellaneous.error_cubes));
-        *get_resource_mut!(resources, DATA_CELLS)? =
-            Property::from(FloatProperty::new(miscellaneous.data_cells));
-        *get_resource_mut!(resources, BLANK_CORES)? =
-            Property::from(FloatProperty::new(miscellaneous.blank_cores));
-        *get_resource_mut!(resources, PHAZYONITE)? =
-            Property::from(FloatProperty::new(miscellaneous.phazyonite));
-
-        *get_mut!(props, "Credits", IntProperty)? = IntProperty::new(miscellaneous.credits);
-        *get_mut!(props, "PerkPoints", IntProperty)? = IntProperty::new(miscellaneous.perk_points);
-
-        // write_gvas(path, gvas)
+    // Reads a section defined outside this crate, `save` validates and writes
+    // it along with the built-in ones. Registering a type again re-reads it.
+    pub fn register<T>(&mut self, gvas: &GvasFile) -> Result<(), Error>
+    where
+        T: SaveSection + Clone + PartialEq + fmt::Debug + 'static,
+    {
+        let section = T::read(gvas).map_err(|e| e.in_section(T::name()))?;
+        self.registered.insert(section);
+
+        Ok(())
+    }
+
+    pub fn section<T: SaveSection + 'static>(&self) -> Option<&T> {
+        self.registered.get()
+    }
+
+    pub fn section_mut<T: SaveSection + 'static>(&mut self) -> Option<&mut T> {
+        self.registered.get_mut()
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        for section in self.sections() {
+            section
+                .validate()
+                .map_err(|e| e.in_section(section.name()))?;
+        }
+
         Ok(())
     }
+
+    pub fn resource(&self, resource: Resource) -> f64 {
+        match resource {
+            Resource::Credits => self.miscellaneous.credits as f64,
+            Resource::PerkPoints => self.miscellaneous.perk_points as f64,
+            Resource::Magnite => self.minerals.magnite as f64,
+            Resource::Bismor => self.minerals.bismor as f64,
+            Resource::Croppa => self.minerals.croppa as f64,
+            Resource::Umanite => self.minerals.umanite as f64,
+            Resource::Jadiz => self.minerals.jadiz as f64,
+            Resource::EnorPearl => self.minerals.enor_pearl as f64,
+            Resource::StarchNut => self.brewing.starch_nut as f64,
+            Resource::YeastCone => self.brewing.yeast_cone as f64,
+            Resource::MaltStar => self.brewing.malt_star as f64,
+            Resource::BarleyBulb => self.brewing.barley_bulb as f64,
+            Resource::ErrorCubes => self.miscellaneous.error_cubes as f64,
+            Resource::DataCells => self.miscellaneous.data_cells as f64,
+            Resource::BlankCores => self.miscellaneous.blank_cores as f64,
+            Resource::Phazyonite => self.miscellaneous.phazyonite as f64,
+        }
+    }
+
+    // Rejects amounts outside `0..=resource.max()`, the same range `validate`
+    // checks, and fractional amounts of integer resources.
+    pub fn set_resource(&mut self, resource: Resource, amount: f64) -> Result<(), Error> {
+        let max = resource.max();
+        // Credits and perk points are the integer resources, the ones without
+        // a Guid in OwnedResources.
+        let reason = if !(0.0..=max).contains(&amount) {
+            Some(format!("{amount} is not in 0..={max}"))
+        } else if resource.guid().is_none() && amount.fract() != 0.0 {
+            Some(format!("{amount} is not a whole number"))
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            let path = match resource.guid() {
+                Some(guid) => format!("Resources.OwnedResources{{{guid}}}"),
+                None if resource == Resource::Credits => "Credits".to_string(),
+                None => "PerkPoints".to_string(),
+            };
+            return Err(ParsingError::invalid_value(&path, &reason).into());
+        }
+
+        match resource {
+            Resource::Credits => self.miscellaneous.credits = amount as i32,
+            Resource::PerkPoints => self.miscellaneous.perk_points = amount as i32,
+            Resource::Magnite => self.minerals.magnite = amount as f32,
+            Resource::Bismor => self.minerals.bismor = amount as f32,
+            Resource::Croppa => self.minerals.croppa = amount as f32,
+            Resource::Umanite => self.minerals.umanite = amount as f32,
+            Resource::Jadiz => self.minerals.jadiz = amount as f32,
+            Resource::EnorPearl => self.minerals.enor_pearl = amount as f32,
+            Resource::StarchNut => self.brewing.starch_nut = amount as f32,
+            Resource::YeastCone => self.brewing.yeast_cone = amount as f32,
+            Resource::MaltStar => self.brewing.malt_star = amount as f32,
+            Resource::BarleyBulb => self.brewing.barley_bulb = amount as f32,
+            Resource::ErrorCubes => self.miscellaneous.error_cubes = amount as f32,
+            Resource::DataCells => self.miscellaneous.data_cells = amount as f32,
+            Resource::BlankCores => self.miscellaneous.blank_cores = amount as f32,
+            Resource::Phazyonite => self.miscellaneous.phazyonite = amount as f32,
+        }
+
+        Ok(())
+    }
+
+    pub fn resources(&self) -> impl Iterator<Item = (Resource, f64)> + '_ {
+        Resource::ALL.into_iter().map(|r| (r, self.resource(r)))
+    }
+
+    pub fn to_bytes(&self, gvas: &mut GvasFile) -> Result<Vec<u8>, Error> {
+        self.save(gvas)?;
+
+        gvas_bytes(gvas)
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    pub fn write_to(&self, gvas: &mut GvasFile, path: &Path) -> Result<(), Error> {
+        self.save(gvas)?;
+
+        write_gvas(path, gvas)
+    }
+
+    pub fn save(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        self.write_sections(gvas, |_| true)
+    }
+
+    // Same as `save` for a save from `from_gvas_lenient`, sections that
+    // couldn't be read are left untouched.
+    pub fn save_loaded(
+        &self,
+        gvas: &mut GvasFile,
+        diagnostics: &[Diagnostic],
+    ) -> Result<(), Error> {
+        self.write_sections(gvas, |section| {
+            !diagnostics
+                .iter()
+                .any(|d| matches!(d, Diagnostic::Skipped(e) if e.section() == Some(section)))
+        })
+    }
+
+    fn write_sections(
+        &self,
+        gvas: &mut GvasFile,
+        loaded: impl Fn(&str) -> bool,
+    ) -> Result<(), Error> {
+        let sections = self
+            .sections()
+            .filter(|s| loaded(s.name()))
+            .collect::<Vec<_>>();
+
+        for section in &sections {
+            section
+                .validate()
+                .map_err(|e| e.in_section(section.name()))?;
+        }
+        for section in &sections {
+            section
+                .write(gvas)
+                .map_err(|e| e.in_section(section.name()))?;
+        }
+
+        Ok(())
+    }
+
+    fn sections(&self) -> impl Iterator<Item = &dyn DynSection> {
+        let built_in: [&dyn DynSection; 16] = [
+            &self.minerals,
+            &self.brewing,
+            &self.miscellaneous,
+            &self.dwarfs,
+            &self.forge,
+            &self.seasons,
+            &self.upgrades,
+            &self.loadouts,
+            &self.enemy_stats,
+            &self.skins,
+            &self.drinks,
+            &self.pickaxe_parts,
+            &self.miners_manual,
+            &self.event_rewards,
+            &self.dlc,
+            &self.mission_parameters,
+        ];
+
+        built_in.into_iter().chain(self.registered.iter())
+    }
+}
+
+fn read_section<T: SaveSection + Default>(
+    gvas: &GvasFile,
+    on_error: &mut impl FnMut(Error) -> Result<(), Error>,
+) -> Result<T, Error> {
+    T::read(gvas).or_else(|e| on_error(e.in_section(T::name())).map(|_| T::default()))
 }
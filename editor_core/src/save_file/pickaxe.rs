@@ -0,0 +1,60 @@
+use gvas::{types::Guid, GvasFile};
+
+use crate::{error::Error, get_guid_set, get_guids, write_guid_set};
+
+use super::section::SaveSection;
+
+// Parts are only known by GUID. Naming them or unlocking a category needs a
+// part table generated from the game assets, which guids_generator doesn't
+// produce yet.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PickaxeParts {
+    pub unlocked: Vec<Guid>,
+}
+
+impl PickaxeParts {
+    pub fn is_unlocked(&self, part: Guid) -> bool {
+        self.unlocked.contains(&part)
+    }
+
+    pub fn unlock(&mut self, part: Guid) -> bool {
+        if self.is_unlocked(part) {
+            return false;
+        }
+
+        self.unlocked.push(part);
+        true
+    }
+
+    pub fn lock(&mut self, part: Guid) -> bool {
+        let len = self.unlocked.len();
+        self.unlocked.retain(|p| *p != part);
+
+        len != self.unlocked.len()
+    }
+
+    // Unlocks every part in `parts`, returns how many were newly unlocked.
+    pub fn unlock_all(&mut self, parts: impl IntoIterator<Item = Guid>) -> usize {
+        parts.into_iter().filter(|p| self.unlock(*p)).count()
+    }
+}
+
+impl SaveSection for PickaxeParts {
+    fn name() -> &'static str {
+        "pickaxe_parts"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let unlocked = match get_guid_set(gvas, "UnlockedPickaxeParts", None)? {
+            Some(set) => get_guids(set, "UnlockedPickaxeParts")?,
+            None => Vec::new(),
+        };
+
+        Ok(Self { unlocked })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        write_guid_set(gvas, "UnlockedPickaxeParts", None, &self.unlocked)
+    }
+}
@@ -0,0 +1,154 @@
+use gvas::{properties::Property, types::Guid, GvasFile};
+
+use crate::{
+    cast, custom_struct, custom_struct_mut, default_value,
+    error::{Error, ParsingError},
+    get_field, get_field_mut, get_guid, get_guids, get_int, guid_property, set_int, write_guid_map,
+};
+
+use super::section::SaveSection;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Season {
+    // Read only, `write` finds the season in the save by it.
+    guid: Guid,
+    pub xp: i32,
+    pub scrip: i32,
+    pub completed_challenges: Vec<Guid>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Seasons {
+    // Private since `write` can only update seasons the save already has.
+    seasons: Vec<Season>,
+}
+
+impl Season {
+    pub fn guid(&self) -> Guid {
+        self.guid
+    }
+
+    pub fn complete_challenge(&mut self, challenge: Guid) {
+        if !self.completed_challenges.contains(&challenge) {
+            self.completed_challenges.push(challenge);
+        }
+    }
+
+    pub fn reset_challenge(&mut self, challenge: Guid) {
+        self.completed_challenges.retain(|c| *c != challenge);
+    }
+}
+
+impl Seasons {
+    pub fn iter(&self) -> impl Iterator<Item = &Season> {
+        self.seasons.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seasons.is_empty()
+    }
+
+    pub fn get(&self, guid: Guid) -> Option<&Season> {
+        self.seasons.iter().find(|s| s.guid == guid)
+    }
+
+    pub fn get_mut(&mut self, guid: Guid) -> Option<&mut Season> {
+        self.seasons.iter_mut().find(|s| s.guid == guid)
+    }
+}
+
+impl SaveSection for Seasons {
+    fn name() -> &'static str {
+        "seasons"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let props = &gvas.properties;
+        let season_save = match props.get("SeasonSave") {
+            Some(p) => custom_struct(p, "SeasonSave")?,
+            None => return Ok(Self::default()),
+        };
+        let path = "SeasonSave.Seasons";
+        let seasons = &cast!(
+            get_field(season_save, "SeasonSave", "Seasons")?,
+            MapProperty,
+            path
+        )?
+        .value;
+
+        let mut parsed = Vec::new();
+        for (i, (k, v)) in seasons.iter().enumerate() {
+            let guid = get_guid(k, &format!("{path}[{i}]"))?;
+            let path = format!("{path}{{{guid}}}");
+            let season = custom_struct(v, &path)?;
+
+            let xp = get_int(season, &path, "XP")?;
+            let scrip = get_int(season, &path, "Scrip")?;
+            let challenges_path = format!("{path}.CompletedSpecialChallenges");
+            let challenges = cast!(
+                get_field(season, &path, "CompletedSpecialChallenges")?,
+                MapProperty,
+                challenges_path
+            )?;
+            let completed_challenges = get_guids(challenges.value.keys(), &challenges_path)?;
+
+            parsed.push(Season {
+                guid,
+                xp,
+                scrip,
+                completed_challenges,
+            });
+        }
+
+        Ok(Self { seasons: parsed })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let props = &mut gvas.properties;
+        if self.seasons.is_empty() && !props.contains_key("SeasonSave") {
+            return Ok(());
+        }
+
+        let season_save = custom_struct_mut(
+            props
+                .get_mut("SeasonSave")
+                .ok_or_else(|| ParsingError::missing_entry("SeasonSave"))?,
+            "SeasonSave",
+        )?;
+        let path = "SeasonSave.Seasons";
+        let seasons = &mut cast!(
+            get_field_mut(season_save, "SeasonSave", "Seasons")?,
+            MapProperty,
+            path
+        )?
+        .value;
+
+        for season in &self.seasons {
+            let path = format!("{path}{{{}}}", season.guid);
+            let fields = custom_struct_mut(
+                seasons
+                    .get_mut(&guid_property(season.guid))
+                    .ok_or_else(|| ParsingError::missing_entry(&path))?,
+                &path,
+            )?;
+
+            set_int(fields, &path, "XP", season.xp)?;
+            set_int(fields, &path, "Scrip", season.scrip)?;
+
+            let challenges_path = format!("{path}.CompletedSpecialChallenges");
+            let challenges = cast!(
+                get_field_mut(fields, &path, "CompletedSpecialChallenges")?,
+                MapProperty,
+                challenges_path
+            )?;
+            let value_type = challenges.value_type.clone();
+            write_guid_map(&mut challenges.value, &season.completed_challenges, || {
+                default_value(&value_type, &challenges_path)
+            })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use std::{any::Any, fmt};
+
+use gvas::GvasFile;
+
+use crate::error::Error;
+
+// A part of the save that's read into its own type and written back, like
+// `Minerals` or `Characters`. Sections defined outside this crate can be added
+// to a `SaveFile` with `SaveFile::register`.
+pub trait SaveSection: Sized {
+    // Tags errors and diagnostics, the built-in sections use their field name
+    // in `SaveFile`.
+    fn name() -> &'static str;
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error>;
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error>;
+
+    // Checked for every section before any of them is written, so a failing
+    // save leaves the GvasFile untouched.
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+// Object safe side of `SaveSection`, so `SaveFile` can hold sections it
+// doesn't know the type of.
+pub(crate) trait DynSection: fmt::Debug {
+    fn name(&self) -> &'static str;
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error>;
+    fn validate(&self) -> Result<(), Error>;
+    fn reread(&self, gvas: &GvasFile) -> Result<Box<dyn DynSection>, Error>;
+    fn clone_box(&self) -> Box<dyn DynSection>;
+    fn eq_dyn(&self, other: &dyn DynSection) -> bool;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T> DynSection for T
+where
+    T: SaveSection + Clone + PartialEq + fmt::Debug + 'static,
+{
+    fn name(&self) -> &'static str {
+        T::name()
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        SaveSection::write(self, gvas)
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        SaveSection::validate(self)
+    }
+
+    fn reread(&self, gvas: &GvasFile) -> Result<Box<dyn DynSection>, Error> {
+        Ok(Box::new(T::read(gvas)?))
+    }
+
+    fn clone_box(&self) -> Box<dyn DynSection> {
+        Box::new(self.clone())
+    }
+
+    fn eq_dyn(&self, other: &dyn DynSection) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+// Sections added with `SaveFile::register`, at most one per type.
+#[derive(Debug, Default)]
+pub(crate) struct RegisteredSections(Vec<Box<dyn DynSection>>);
+
+impl RegisteredSections {
+    pub(crate) fn insert<T: DynSection + 'static>(&mut self, section: T) {
+        match self.0.iter_mut().find(|s| s.as_any().is::<T>()) {
+            Some(s) => *s = Box::new(section),
+            None => self.0.push(Box::new(section)),
+        }
+    }
+
+    pub(crate) fn get<T: 'static>(&self) -> Option<&T> {
+        self.0.iter().find_map(|s| s.as_any().downcast_ref())
+    }
+
+    pub(crate) fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.0
+            .iter_mut()
+            .find_map(|s| s.as_any_mut().downcast_mut())
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &dyn DynSection> {
+        self.0.iter().map(|s| s.as_ref())
+    }
+
+    // Reads every registered section again from `gvas`, e.g. on revert.
+    pub(crate) fn reread(&self, gvas: &GvasFile) -> Result<Self, Error> {
+        self.0
+            .iter()
+            .map(|s| s.reread(gvas).map_err(|e| e.in_section(s.name())))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl Clone for RegisteredSections {
+    fn clone(&self) -> Self {
+        Self(self.0.iter().map(|s| s.clone_box()).collect())
+    }
+}
+
+impl PartialEq for RegisteredSections {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.eq_dyn(b.as_ref()))
+    }
+}
@@ -0,0 +1,163 @@
+use gvas::{
+    properties::{
+        map_property::MapProperty,
+        set_property::SetProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        Property,
+    },
+    types::Guid,
+    GvasFile,
+};
+use indexmap::IndexMap;
+
+use crate::{
+    cast, custom_struct, custom_struct_mut,
+    error::{Error, ParsingError},
+    get_field, get_field_mut, get_guid, get_guids, guid_property, update_guid_set,
+};
+
+use super::section::SaveSection;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemSkins {
+    pub item: Guid,
+    pub skins: Vec<Guid>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Skins {
+    pub items: Vec<ItemSkins>,
+}
+
+impl Skins {
+    pub fn get(&self, item: Guid) -> Option<&ItemSkins> {
+        self.items.iter().find(|i| i.item == item)
+    }
+
+    pub fn is_unlocked(&self, item: Guid, skin: Guid) -> bool {
+        self.get(item).is_some_and(|i| i.skins.contains(&skin))
+    }
+
+    pub fn unlock(&mut self, item: Guid, skin: Guid) -> bool {
+        if self.is_unlocked(item, skin) {
+            return false;
+        }
+
+        match self.items.iter_mut().find(|i| i.item == item) {
+            Some(i) => i.skins.push(skin),
+            None => self.items.push(ItemSkins {
+                item,
+                skins: vec![skin],
+            }),
+        }
+        true
+    }
+
+    pub fn lock(&mut self, item: Guid, skin: Guid) -> bool {
+        match self.items.iter_mut().find(|i| i.item == item) {
+            Some(i) if i.skins.contains(&skin) => {
+                i.skins.retain(|s| *s != skin);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl SaveSection for Skins {
+    fn name() -> &'static str {
+        "skins"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let props = &gvas.properties;
+        let unlocked_skins = match props.get("UnlockedItemSkins") {
+            Some(p) => &cast!(p, MapProperty, "UnlockedItemSkins")?.value,
+            None => return Ok(Self::default()),
+        };
+
+        let mut items = Vec::new();
+        for (i, (k, v)) in unlocked_skins.iter().enumerate() {
+            let item = get_guid(k, &format!("UnlockedItemSkins[{i}]"))?;
+            let path = format!("UnlockedItemSkins{{{item}}}");
+            let skins = get_guids(get_skins(v, &path)?, &format!("{path}.Skins"))?;
+
+            items.push(ItemSkins { item, skins });
+        }
+
+        Ok(Self { items })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        // gvas can't read empty sets back, so items without skins lose their
+        // entry instead.
+        let unlocked = || self.items.iter().filter(|i| !i.skins.is_empty());
+
+        let props = &mut gvas.properties;
+        if unlocked().next().is_none() && !props.contains_key("UnlockedItemSkins") {
+            return Ok(());
+        }
+
+        let unlocked_skins = props
+            .entry("UnlockedItemSkins".to_string())
+            .or_insert_with(|| {
+                Property::from(MapProperty::new(
+                    "StructProperty".to_string(),
+                    "StructProperty".to_string(),
+                    0,
+                    IndexMap::new(),
+                ))
+            });
+        let unlocked_skins = &mut cast!(unlocked_skins, MapProperty, "UnlockedItemSkins")?.value;
+
+        unlocked_skins
+            .retain(|k, _| get_guid(k, "").map_or(true, |g| unlocked().any(|i| i.item == g)));
+        for item in unlocked() {
+            let path = format!("UnlockedItemSkins{{{}}}", item.item);
+            match unlocked_skins.get_mut(&guid_property(item.item)) {
+                Some(p) => update_guid_set(get_skins_mut(p, &path)?, &item.skins),
+                None => {
+                    unlocked_skins.insert(guid_property(item.item), item_skins(&item.skins));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// The UnlockedItemSkins entry of an item that had no skins yet.
+fn item_skins(skins: &[Guid]) -> Property {
+    let skins = SetProperty::new(
+        "StructProperty".to_string(),
+        0,
+        skins.iter().copied().map(guid_property).collect(),
+    );
+
+    Property::from(StructProperty::new(
+        Guid::from(0),
+        StructPropertyValue::CustomStruct(
+            "UnlockedItemSkins".to_string(),
+            vec![("Skins".to_string(), Property::from(skins))],
+        ),
+    ))
+}
+
+fn get_skins<'a>(property: &'a Property, path: &str) -> Result<&'a Vec<Property>, Error> {
+    let fields = custom_struct(property, path)?;
+    let skins = get_field(fields, path, "Skins")?;
+
+    Ok(&cast!(skins, SetProperty, format!("{path}.Skins"))?.properties)
+}
+
+fn get_skins_mut<'a>(
+    property: &'a mut Property,
+    path: &str,
+) -> Result<&'a mut Vec<Property>, Error> {
+    let fields = custom_struct_mut(property, path)?;
+    let skins = get_field_mut(fields, path, "Skins")?;
+
+    Ok(&mut cast!(skins, SetProperty, format!("{path}.Skins"))?.properties)
+}
@@ -0,0 +1,199 @@
+use gvas::{
+    properties::{
+        array_property::ArrayProperty,
+        int_property::IntProperty,
+        map_property::MapProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        Property,
+    },
+    types::Guid,
+    GvasFile,
+};
+use indexmap::IndexMap;
+
+use crate::{
+    cast, custom_struct, custom_struct_mut,
+    error::{struct_type, Error, ParsingError},
+    get_field, get_field_mut, get_guid, guid_property,
+};
+
+use super::section::SaveSection;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpgradeSelection {
+    pub item: Guid,
+    // Selected upgrade per tier, -1 when the tier has no selection.
+    pub tiers: Vec<i32>,
+    pub overclock: Option<Guid>,
+}
+
+// Selections added to or removed from `selections` are added to or removed
+// from the save on write.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Upgrades {
+    pub selections: Vec<UpgradeSelection>,
+}
+
+impl UpgradeSelection {
+    pub fn set_tier(&mut self, tier: usize, upgrade: Option<i32>) {
+        if let Some(t) = self.tiers.get_mut(tier) {
+            *t = upgrade.unwrap_or(-1);
+        }
+    }
+
+    pub(crate) fn from_property(
+        item: Guid,
+        property: &Property,
+        path: &str,
+    ) -> Result<Self, Error> {
+        let fields = custom_struct(property, path)?;
+
+        let tiers_path = format!("{path}.UpgradeTiers");
+        let tiers = &cast!(
+            get_field(fields, path, "UpgradeTiers")?,
+            ArrayProperty,
+            tiers_path
+        )?
+        .properties;
+        let tiers = tiers
+            .iter()
+            .enumerate()
+            .map(|(i, p)| Ok(cast!(p, IntProperty, format!("{tiers_path}[{i}]"))?.value))
+            .collect::<Result<_, Error>>()?;
+        let overclock = get_guid(
+            get_field(fields, path, "Overclock")?,
+            &format!("{path}.Overclock"),
+        )?;
+
+        Ok(Self {
+            item,
+            tiers,
+            overclock: Some(overclock).filter(|g| !g.is_zero()),
+        })
+    }
+
+    // An ItemUpgradeSelection for items the save has no entry for yet.
+    pub(crate) fn to_property(&self) -> Property {
+        let tiers = self
+            .tiers
+            .iter()
+            .map(|t| Property::from(IntProperty::new(*t)))
+            .collect();
+        let fields = vec![
+            (
+                "UpgradeTiers".to_string(),
+                Property::from(ArrayProperty::new("IntProperty".to_string(), None, tiers)),
+            ),
+            (
+                "Overclock".to_string(),
+                guid_property(self.overclock.unwrap_or_default()),
+            ),
+        ];
+
+        Property::from(StructProperty::new(
+            Guid::from(0),
+            StructPropertyValue::CustomStruct("ItemUpgradeSelection".to_string(), fields),
+        ))
+    }
+
+    pub(crate) fn write(&self, property: &mut Property, path: &str) -> Result<(), Error> {
+        let fields = custom_struct_mut(property, path)?;
+
+        let tiers = get_field_mut(fields, path, "UpgradeTiers")?;
+        cast!(tiers, ArrayProperty, format!("{path}.UpgradeTiers"))?.properties = self
+            .tiers
+            .iter()
+            .map(|t| Property::from(IntProperty::new(*t)))
+            .collect();
+
+        let overclock_path = format!("{path}.Overclock");
+        let overclock = cast!(
+            get_field_mut(fields, path, "Overclock")?,
+            StructProperty,
+            overclock_path
+        )?;
+        match &mut overclock.value {
+            StructPropertyValue::Guid(guid) => *guid = self.overclock.unwrap_or_default(),
+            v => Err(ParsingError::failed_cast(
+                &overclock_path,
+                "Guid",
+                struct_type(v),
+            ))?,
+        }
+
+        Ok(())
+    }
+}
+
+impl Upgrades {
+    pub fn get(&self, item: Guid) -> Option<&UpgradeSelection> {
+        self.selections.iter().find(|s| s.item == item)
+    }
+
+    pub fn get_mut(&mut self, item: Guid) -> Option<&mut UpgradeSelection> {
+        self.selections.iter_mut().find(|s| s.item == item)
+    }
+}
+
+impl SaveSection for Upgrades {
+    fn name() -> &'static str {
+        "upgrades"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        let props = &gvas.properties;
+        let selections = match props.get("ItemUpgradeSelections") {
+            Some(p) => &cast!(p, MapProperty, "ItemUpgradeSelections")?.value,
+            None => return Ok(Self::default()),
+        };
+
+        let selections = selections
+            .iter()
+            .enumerate()
+            .map(|(i, (k, v))| {
+                let item = get_guid(k, &format!("ItemUpgradeSelections[{i}]"))?;
+                UpgradeSelection::from_property(
+                    item,
+                    v,
+                    &format!("ItemUpgradeSelections{{{item}}}"),
+                )
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { selections })
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        let props = &mut gvas.properties;
+        if self.selections.is_empty() && !props.contains_key("ItemUpgradeSelections") {
+            return Ok(());
+        }
+
+        let selections = props
+            .entry("ItemUpgradeSelections".to_string())
+            .or_insert_with(|| {
+                Property::from(MapProperty::new(
+                    "StructProperty".to_string(),
+                    "StructProperty".to_string(),
+                    0,
+                    IndexMap::new(),
+                ))
+            });
+        let selections = &mut cast!(selections, MapProperty, "ItemUpgradeSelections")?.value;
+
+        selections.retain(|k, _| get_guid(k, "").map_or(true, |g| self.get(g).is_some()));
+        for selection in &self.selections {
+            let path = format!("ItemUpgradeSelections{{{}}}", selection.item);
+            match selections.get_mut(&guid_property(selection.item)) {
+                Some(p) => selection.write(p, &path)?,
+                None => {
+                    selections.insert(guid_property(selection.item), selection.to_property());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,460 @@
+#![allow(dead_code)]
+
+use gvas::{
+    properties::{
+        array_property::ArrayProperty,
+        int_property::{BoolProperty, FloatProperty, IntProperty},
+        map_property::MapProperty,
+        set_property::SetProperty,
+        struct_property::{StructProperty, StructPropertyValue},
+        Property,
+    },
+    types::Guid,
+    FEngineVersion, GvasFile, GvasHeader,
+};
+use indexmap::IndexMap;
+
+use editor_core::{
+    error::{Error, ParsingError},
+    gvas_bytes, read_gvas_bytes,
+    registry::{
+        BARLEY_BULB, BISMOR, BLANK_CORES, CROPPA, DATA_CELLS, DRILLER, ENGINEER, ENOR_PEARL,
+        ERROR_CUBES, GUNNER, JADIZ, MAGNITE, MALT_STAR, PHAZYONITE, SCOUT, STARCH_NUT, UMANITE,
+        YEAST_CONE,
+    },
+    save_file::{section::SaveSection, SaveFile},
+};
+
+// "Turret Arc" and "Turret EM Discharge" from registry::SCHEMATICS.
+pub const OWNED_SCHEMATIC: Guid = Guid([
+    60, 66, 219, 234, 48, 33, 238, 68, 175, 109, 148, 74, 145, 49, 77, 117,
+]);
+pub const FORGED_SCHEMATIC: Guid = Guid([
+    12, 27, 170, 100, 176, 60, 185, 78, 187, 119, 183, 181, 94, 124, 108, 135,
+]);
+pub const UNKNOWN_SCHEMATIC: Guid = Guid([0xEE; 16]);
+
+pub const SEASON: Guid = Guid([0x5E; 16]);
+pub const CHALLENGE: Guid = Guid([0xC0; 16]);
+
+pub const WEAPON: Guid = Guid([0x3A; 16]);
+
+pub const PISTOL: Guid = Guid([0x3B; 16]);
+
+pub const GLYPHID_GRUNT: Guid = Guid([0x61; 16]);
+pub const MACTERA_SPAWN: Guid = Guid([0x62; 16]);
+
+pub const FRAMEWORK: Guid = Guid([0xF1; 16]);
+pub const PAINT_JOB: Guid = Guid([0xF2; 16]);
+
+pub const LEAF_LOVERS_SPECIAL: Guid = Guid([0xD1; 16]);
+pub const BLACKOUT_STOUT: Guid = Guid([0xD2; 16]);
+
+pub const PICKAXE_BLADE: Guid = Guid([0xB1; 16]);
+pub const PICKAXE_POMMEL: Guid = Guid([0xB2; 16]);
+
+pub const MANUAL_GLYPHID: Guid = Guid([0xA1; 16]);
+pub const MANUAL_NITRA: Guid = Guid([0xA2; 16]);
+
+pub const EVENT: Guid = Guid([0xE1; 16]);
+pub const POPUP: Guid = Guid([0xE2; 16]);
+
+pub const DLC: Guid = Guid([0xD7; 16]);
+
+pub const MISSION_PARAMETER: Guid = Guid([0x4D; 16]);
+
+pub const RESOURCES: [(Guid, f32); 14] = [
+    (MAGNITE, 100.0),
+    (BISMOR, 200.0),
+    (CROPPA, 300.0),
+    (UMANITE, 400.0),
+    (JADIZ, 500.0),
+    (ENOR_PEARL, 600.0),
+    (STARCH_NUT, 10.0),
+    (YEAST_CONE, 20.0),
+    (MALT_STAR, 30.0),
+    (BARLEY_BULB, 40.0),
+    (ERROR_CUBES, 1.0),
+    (DATA_CELLS, 2.0),
+    (BLANK_CORES, 3.0),
+    (PHAZYONITE, 4.0),
+];
+
+pub fn guid_struct(guid: Guid) -> Property {
+    Property::from(StructProperty::new(
+        Guid::default(),
+        StructPropertyValue::Guid(guid),
+    ))
+}
+
+pub fn custom_struct(name: &str, fields: Vec<(&str, Property)>) -> Property {
+    let fields = fields
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+
+    Property::from(StructProperty::new(
+        Guid::default(),
+        StructPropertyValue::CustomStruct(name.to_string(), fields),
+    ))
+}
+
+pub fn struct_array(field: &str, type_name: &str, properties: Vec<Property>) -> Property {
+    Property::from(ArrayProperty::new(
+        "StructProperty".to_string(),
+        Some((field.to_string(), type_name.to_string(), Guid::default())),
+        properties,
+    ))
+}
+
+pub fn resources(resources: &[(Guid, f32)]) -> Property {
+    let owned_resources = resources
+        .iter()
+        .map(|(guid, amount)| {
+            (
+                guid_struct(*guid),
+                Property::from(FloatProperty::new(*amount)),
+            )
+        })
+        .collect::<IndexMap<_, _>>();
+
+    custom_struct(
+        "ResourcesSave",
+        vec![(
+            "OwnedResources",
+            Property::from(MapProperty::new(
+                "StructProperty".to_string(),
+                "FloatProperty".to_string(),
+                0,
+                owned_resources,
+            )),
+        )],
+    )
+}
+
+pub fn character(id: Guid, xp: i32, times_retired: i32) -> Property {
+    custom_struct(
+        "CharacterSave",
+        vec![
+            ("SavegameID", guid_struct(id)),
+            ("XP", Property::from(IntProperty::new(xp))),
+            (
+                "TimesRetired",
+                Property::from(IntProperty::new(times_retired)),
+            ),
+        ],
+    )
+}
+
+pub fn schematics(owned: &[Guid], forged: &[Guid]) -> Property {
+    let array = |name: &str, guids: &[Guid]| {
+        struct_array(
+            name,
+            "Guid",
+            guids.iter().copied().map(guid_struct).collect(),
+        )
+    };
+
+    custom_struct(
+        "SchematicSave",
+        vec![
+            ("OwnedSchematics", array("OwnedSchematics", owned)),
+            ("ForgedSchematics", array("ForgedSchematics", forged)),
+        ],
+    )
+}
+
+pub fn guid_map(value_type: &str, entries: Vec<(Guid, Property)>) -> Property {
+    Property::from(MapProperty::new(
+        "StructProperty".to_string(),
+        value_type.to_string(),
+        0,
+        entries
+            .into_iter()
+            .map(|(k, v)| (guid_struct(k), v))
+            .collect(),
+    ))
+}
+
+pub fn season(xp: i32, scrip: i32, challenges: &[Guid]) -> Property {
+    let challenges = challenges
+        .iter()
+        .map(|c| (*c, Property::from(BoolProperty::new(true))))
+        .collect();
+
+    custom_struct(
+        "SeasonSaveEntry",
+        vec![
+            ("XP", Property::from(IntProperty::new(xp))),
+            ("Scrip", Property::from(IntProperty::new(scrip))),
+            (
+                "CompletedSpecialChallenges",
+                guid_map("BoolProperty", challenges),
+            ),
+        ],
+    )
+}
+
+pub fn seasons(seasons: Vec<(Guid, Property)>) -> Property {
+    custom_struct(
+        "SeasonSave",
+        vec![("Seasons", guid_map("StructProperty", seasons))],
+    )
+}
+
+pub fn upgrade_selection(tiers: &[i32], overclock: Guid) -> Property {
+    custom_struct(
+        "ItemUpgradeSelection",
+        vec![
+            (
+                "UpgradeTiers",
+                Property::from(ArrayProperty::new(
+                    "IntProperty".to_string(),
+                    None,
+                    tiers
+                        .iter()
+                        .map(|t| Property::from(IntProperty::new(*t)))
+                        .collect(),
+                )),
+            ),
+            ("Overclock", guid_struct(overclock)),
+        ],
+    )
+}
+
+pub fn loadout(items: Vec<(Guid, Property)>) -> Property {
+    custom_struct(
+        "ItemUpgradeLoadout",
+        vec![("Loadout", guid_map("StructProperty", items))],
+    )
+}
+
+pub fn character_loadout(
+    character: Guid,
+    cosmetics: &[Guid],
+    items: Vec<(Guid, Property)>,
+) -> Property {
+    custom_struct(
+        "ItemUpgradeLoadout",
+        vec![
+            ("Loadout", guid_map("StructProperty", items)),
+            ("CharacterID", guid_struct(character)),
+            (
+                "VanityItems",
+                struct_array(
+                    "VanityItems",
+                    "Guid",
+                    cosmetics.iter().copied().map(guid_struct).collect(),
+                ),
+            ),
+        ],
+    )
+}
+
+pub fn guid_set(guids: &[Guid]) -> Property {
+    Property::from(SetProperty::new(
+        "StructProperty".to_string(),
+        0,
+        guids.iter().copied().map(guid_struct).collect(),
+    ))
+}
+
+pub fn item_skins(skins: &[Guid]) -> Property {
+    custom_struct("UnlockedItemSkins", vec![("Skins", guid_set(skins))])
+}
+
+pub fn header() -> GvasHeader {
+    GvasHeader::Version2 {
+        package_file_version: 522,
+        engine_version: FEngineVersion::new(4, 27, 2, 0, "++UE4+Release-4.27".to_string()),
+        custom_version_format: 3,
+        custom_versions: vec![],
+        save_game_class_name: "/Script/FSD.FSDSaveGame".to_string(),
+    }
+}
+
+pub fn gvas() -> GvasFile {
+    let mut properties = IndexMap::new();
+    properties.insert(
+        "Credits".to_string(),
+        Property::from(IntProperty::new(1000)),
+    );
+    properties.insert(
+        "PerkPoints".to_string(),
+        Property::from(IntProperty::new(7)),
+    );
+    properties.insert("Resources".to_string(), resources(&RESOURCES));
+    properties.insert(
+        "CharacterSaves".to_string(),
+        struct_array(
+            "CharacterSaves",
+            "CharacterSave",
+            vec![
+                character(ENGINEER, 3500, 0),
+                character(SCOUT, 315000, 2),
+                character(DRILLER, 12000, 19),
+                character(GUNNER, 0, 0),
+            ],
+        ),
+    );
+    properties.insert(
+        "SchematicSave".to_string(),
+        schematics(&[OWNED_SCHEMATIC, UNKNOWN_SCHEMATIC], &[FORGED_SCHEMATIC]),
+    );
+    properties.insert(
+        "SeasonSave".to_string(),
+        seasons(vec![(SEASON, season(1500, 20, &[CHALLENGE]))]),
+    );
+    properties.insert(
+        "ItemUpgradeSelections".to_string(),
+        guid_map(
+            "StructProperty",
+            vec![(
+                WEAPON,
+                upgrade_selection(&[0, 1, -1, 2, -1], Guid::default()),
+            )],
+        ),
+    );
+    properties.insert(
+        "ItemUpgradeLoadouts".to_string(),
+        struct_array(
+            "ItemUpgradeLoadouts",
+            "ItemUpgradeLoadout",
+            vec![
+                loadout(vec![
+                    (WEAPON, upgrade_selection(&[0, 0, 0], Guid::default())),
+                    (PISTOL, upgrade_selection(&[1, 1, 1], OWNED_SCHEMATIC)),
+                ]),
+                loadout(vec![(
+                    WEAPON,
+                    upgrade_selection(&[2, 2, 2], FORGED_SCHEMATIC),
+                )]),
+            ],
+        ),
+    );
+    properties.insert(
+        "EnemiesKilled".to_string(),
+        guid_map(
+            "IntProperty",
+            vec![
+                (GLYPHID_GRUNT, Property::from(IntProperty::new(1200))),
+                (MACTERA_SPAWN, Property::from(IntProperty::new(34))),
+            ],
+        ),
+    );
+    properties.insert(
+        "UnlockedItemSkins".to_string(),
+        guid_map(
+            "StructProperty",
+            vec![
+                (WEAPON, item_skins(&[FRAMEWORK, PAINT_JOB])),
+                (PISTOL, item_skins(&[PAINT_JOB])),
+            ],
+        ),
+    );
+    properties.insert(
+        "Drinks".to_string(),
+        custom_struct(
+            "DrinkSave",
+            vec![("UnlockedDrinks", guid_set(&[LEAF_LOVERS_SPECIAL]))],
+        ),
+    );
+    properties.insert(
+        "UnlockedPickaxeParts".to_string(),
+        guid_set(&[PICKAXE_BLADE, PICKAXE_POMMEL]),
+    );
+    properties.insert(
+        "MinersManualKnownObjects".to_string(),
+        guid_set(&[MANUAL_GLYPHID, MANUAL_NITRA]),
+    );
+    properties.insert(
+        "FSDEventRewardsSave".to_string(),
+        custom_struct(
+            "FSDEventRewardsSave",
+            vec![
+                ("EventsSeen", guid_set(&[EVENT])),
+                ("PopupsSeen", guid_set(&[POPUP])),
+            ],
+        ),
+    );
+    properties.insert(
+        "GameDLCSave".to_string(),
+        custom_struct("GameDLCSave", vec![("AnnouncedIDs", guid_set(&[DLC]))]),
+    );
+    properties.insert(
+        "UnLockedMissionParameters".to_string(),
+        guid_map(
+            "StructProperty",
+            vec![(
+                MISSION_PARAMETER,
+                custom_struct(
+                    "MissionParameterUnlock",
+                    vec![("Seen", Property::from(BoolProperty::new(true)))],
+                ),
+            )],
+        ),
+    );
+
+    GvasFile {
+        header: header(),
+        properties,
+    }
+}
+
+pub fn bytes(gvas: &GvasFile) -> Vec<u8> {
+    gvas_bytes(gvas).expect("serializable fixture")
+}
+
+// Reads `gvas` through its serialized bytes, like a save loaded from disk.
+pub fn load(gvas: &GvasFile) -> SaveFile {
+    SaveFile::from_bytes(&bytes(gvas)).unwrap()
+}
+
+// Writes `save` over a fresh copy of the file it was loaded from.
+pub fn resave(gvas: &GvasFile, save: &SaveFile) -> Vec<u8> {
+    let mut gvas = read_gvas_bytes(&bytes(gvas)).unwrap();
+    save.to_bytes(&mut gvas).unwrap()
+}
+
+// Writes `save` and loads the output again.
+pub fn reload(gvas: &GvasFile, save: &SaveFile) -> SaveFile {
+    SaveFile::from_bytes(&resave(gvas, save)).unwrap()
+}
+
+// A section the editor doesn't know about, registered like a third-party one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mutators {
+    pub level: i32,
+}
+
+impl SaveSection for Mutators {
+    fn name() -> &'static str {
+        "mutators"
+    }
+
+    fn read(gvas: &GvasFile) -> Result<Self, Error> {
+        match gvas.properties.get("MutatorLevel") {
+            Some(Property::IntProperty(p)) => Ok(Self { level: p.value }),
+            Some(_) => {
+                Err(ParsingError::failed_cast("MutatorLevel", "IntProperty", "other").into())
+            }
+            None => Err(ParsingError::missing_entry("MutatorLevel").into()),
+        }
+    }
+
+    fn write(&self, gvas: &mut GvasFile) -> Result<(), Error> {
+        gvas.properties.insert(
+            "MutatorLevel".to_string(),
+            Property::from(IntProperty::new(self.level)),
+        );
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        match self.level {
+            0.. => Ok(()),
+            _ => Err(ParsingError::invalid_value("MutatorLevel", "negative level").into()),
+        }
+    }
+}
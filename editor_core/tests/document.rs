@@ -0,0 +1,165 @@
+mod common;
+
+use gvas::{
+    properties::{
+        int_property::{FloatProperty, IntProperty},
+        Property,
+    },
+    types::Guid,
+};
+
+use editor_core::{document::SaveDocument, error::Diagnostic};
+
+use common::Mutators;
+
+#[test]
+fn edits_mark_the_document_dirty() {
+    let mut document = SaveDocument::from_bytes(common::bytes(&common::gvas())).unwrap();
+    assert!(!document.is_dirty());
+
+    document.save_mut().minerals.croppa = 1.0;
+
+    assert!(document.is_dirty());
+}
+
+#[test]
+fn revert_restores_the_source() {
+    let bytes = common::bytes(&common::gvas());
+    let mut document = SaveDocument::from_bytes(bytes.clone()).unwrap();
+
+    document.save_mut().minerals.croppa = 1.0;
+    document.apply().unwrap();
+    document.revert().unwrap();
+
+    assert!(!document.is_dirty());
+    assert_eq!(document.save().minerals.croppa, 300.0);
+    assert_eq!(document.write().unwrap(), bytes);
+}
+
+#[test]
+fn write_makes_the_output_the_new_source() {
+    let mut document = SaveDocument::from_bytes(common::bytes(&common::gvas())).unwrap();
+
+    document.save_mut().dwarfs.scout.xp = 0;
+    let bytes = document.write().unwrap();
+    document.save_mut().dwarfs.scout.xp = 1;
+    document.revert().unwrap();
+
+    assert!(!document.is_dirty());
+    assert_eq!(document.source(), &bytes[..]);
+    assert_eq!(document.save().dwarfs.scout.xp, 0);
+}
+
+#[test]
+#[cfg(not(feature = "wasm"))]
+fn write_to_records_the_path() {
+    let path = std::env::temp_dir().join(format!("editor_core_doc_{}.sav", std::process::id()));
+    let mut document = SaveDocument::from_bytes(common::bytes(&common::gvas())).unwrap();
+
+    document.save_mut().miscellaneous.credits = 5;
+    document.write_to(&path).unwrap();
+    let loaded = SaveDocument::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let loaded = loaded.unwrap();
+    assert_eq!(document.path(), Some(path.as_path()));
+    assert_eq!(loaded.path(), Some(path.as_path()));
+    assert_eq!(loaded.save(), document.save());
+}
+
+#[test]
+fn lenient_load_keeps_the_sections_that_parsed() {
+    let mut gvas = common::gvas();
+    let corrupt = common::seasons(vec![(
+        common::SEASON,
+        common::custom_struct(
+            "SeasonSaveEntry",
+            vec![("XP", Property::from(FloatProperty::new(1.0)))],
+        ),
+    )]);
+    gvas.properties.insert("SeasonSave".to_string(), corrupt);
+    let bytes = common::bytes(&gvas);
+    assert!(SaveDocument::from_bytes(bytes.clone()).is_err());
+
+    let mut document = SaveDocument::from_bytes_lenient(bytes).unwrap();
+    let season_save = document.gvas().properties["SeasonSave"].clone();
+
+    let sections = document
+        .diagnostics()
+        .iter()
+        .map(|d| d.section().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(sections, ["seasons", "forge"]);
+    assert!(document.save().seasons.is_empty());
+    assert_eq!(document.save().brewing.malt_star, 30.0);
+
+    document.save_mut().brewing.malt_star = 5.0;
+    let reread = SaveDocument::from_bytes_lenient(document.write().unwrap()).unwrap();
+
+    assert_eq!(reread.save().brewing.malt_star, 5.0);
+    assert_eq!(reread.gvas().properties["SeasonSave"], season_save);
+    assert_eq!(reread.diagnostics(), document.diagnostics());
+}
+
+#[test]
+fn revert_reads_registered_sections_again() {
+    let mut gvas = common::gvas();
+    gvas.properties.insert(
+        "MutatorLevel".to_string(),
+        Property::from(IntProperty::new(3)),
+    );
+    let mut document = SaveDocument::from_bytes(common::bytes(&gvas)).unwrap();
+    document.register::<Mutators>().unwrap();
+
+    document.save_mut().section_mut::<Mutators>().unwrap().level = 9;
+    document.revert().unwrap();
+
+    assert_eq!(
+        document.save().section::<Mutators>(),
+        Some(&Mutators { level: 3 })
+    );
+}
+
+#[test]
+fn unknown_guids_are_reported() {
+    let mut gvas = common::gvas();
+    let resource = Guid([0x7E; 16]);
+    let mut resources = common::RESOURCES.to_vec();
+    resources.push((resource, 1.0));
+    gvas.properties
+        .insert("Resources".to_string(), common::resources(&resources));
+
+    let document = SaveDocument::from_bytes_lenient(common::bytes(&gvas)).unwrap();
+
+    assert_eq!(
+        document.diagnostics(),
+        [
+            Diagnostic::UnknownGuid {
+                section: "forge".to_string(),
+                path: "SchematicSave".to_string(),
+                guid: common::UNKNOWN_SCHEMATIC,
+            },
+            Diagnostic::UnknownGuid {
+                section: "miscellaneous".to_string(),
+                path: "Resources.OwnedResources".to_string(),
+                guid: resource,
+            },
+        ]
+    );
+}
+
+#[test]
+fn revert_keeps_the_load_mode() {
+    let bytes = common::bytes(&common::gvas());
+    let mut strict = SaveDocument::from_bytes(bytes.clone()).unwrap();
+    let mut lenient = SaveDocument::from_bytes_lenient(bytes).unwrap();
+    let diagnostics = lenient.diagnostics().to_vec();
+
+    strict.revert().unwrap();
+    lenient.revert().unwrap();
+
+    assert!(!strict.is_lenient());
+    assert!(strict.diagnostics().is_empty());
+    assert!(lenient.is_lenient());
+    assert_eq!(lenient.diagnostics(), diagnostics);
+}
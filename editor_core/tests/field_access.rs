@@ -0,0 +1,129 @@
+#![cfg(feature = "field_access")]
+
+mod common;
+
+use editor_core::{
+    error::FieldError,
+    registry::{MAX_F32, XP_TABLE},
+    save_file::{
+        dwarfs::Rank,
+        field_access::{Value, ValueType},
+        SaveFile,
+    },
+};
+
+#[test]
+fn fields_are_read_by_path() {
+    let save = SaveFile::from_gvas(&common::gvas()).unwrap();
+
+    assert_eq!(
+        save.get_path("minerals.magnite").unwrap(),
+        Value::Float(save.minerals.magnite)
+    );
+    assert_eq!(
+        save.get_path("miscellaneous.credits").unwrap(),
+        Value::Int(save.miscellaneous.credits)
+    );
+    assert_eq!(
+        save.get_path("dwarfs.scout.xp").unwrap(),
+        Value::Int(315000)
+    );
+    assert_eq!(
+        save.get_path(&format!("seasons.{}.scrip", common::SEASON))
+            .unwrap(),
+        Value::Int(20)
+    );
+    assert_eq!(
+        save.get_path("minerals.gold"),
+        Err(FieldError::UnknownPath("minerals.gold".to_string()))
+    );
+}
+
+#[test]
+fn setting_xp_by_path_updates_the_rank() {
+    let mut save = SaveFile::from_gvas(&common::gvas()).unwrap();
+
+    save.set_path("dwarfs.gunner.xp", Value::Int(XP_TABLE[5]))
+        .unwrap();
+    save.set_path("dwarfs.gunner.times_retired", Value::Int(3))
+        .unwrap();
+    save.set_path("minerals.bismor", Value::Float(42.0))
+        .unwrap();
+
+    assert_eq!(save.dwarfs.gunner.xp_to_level(), (6, 0));
+    assert_eq!(save.dwarfs.gunner, Rank::new(XP_TABLE[5], 3));
+    assert_eq!(save.minerals.bismor, 42.0);
+}
+
+#[test]
+fn every_dwarf_path_edits_its_own_dwarf() {
+    let mut save = SaveFile::from_gvas(&common::gvas()).unwrap();
+
+    for (i, dwarf) in ["engineer", "driller", "gunner", "scout"]
+        .iter()
+        .enumerate()
+    {
+        save.set_path(
+            &format!("dwarfs.{dwarf}.times_retired"),
+            Value::Int(i as i32 + 1),
+        )
+        .unwrap();
+    }
+
+    let dwarfs = &save.dwarfs;
+    assert_eq!(
+        [
+            dwarfs.engineer.times_retired,
+            dwarfs.driller.times_retired,
+            dwarfs.gunner.times_retired,
+            dwarfs.scout.times_retired,
+        ],
+        [1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    let mut save = SaveFile::from_gvas(&common::gvas()).unwrap();
+    let before = save.clone();
+
+    assert_eq!(
+        save.set_path("dwarfs.scout.xp", Value::Float(1.0)),
+        Err(FieldError::TypeMismatch {
+            path: "dwarfs.scout.xp".to_string(),
+            expected: ValueType::Int,
+            found: ValueType::Float,
+        })
+    );
+    assert!(matches!(
+        save.set_path("minerals.jadiz", Value::Float(-1.0)),
+        Err(FieldError::OutOfBounds { .. })
+    ));
+    assert!(matches!(
+        save.set_path("dwarfs.driller.xp", Value::Int(XP_TABLE[24] + 1)),
+        Err(FieldError::OutOfBounds { .. })
+    ));
+    assert_eq!(save, before);
+}
+
+#[test]
+fn every_listed_path_can_be_read_and_set_to_its_max() {
+    let mut save = SaveFile::from_gvas(&common::gvas()).unwrap();
+    let paths = save.paths();
+
+    for field in &paths {
+        let value = save.get_path(&field.path).unwrap();
+        assert_eq!(value.ty(), field.ty, "{}", field.path);
+
+        let max = match field.ty {
+            ValueType::Int => Value::Int(field.max as i32),
+            ValueType::Float => Value::Float(field.max as f32),
+        };
+        save.set_path(&field.path, max).unwrap();
+        assert_eq!(save.get_path(&field.path).unwrap(), max, "{}", field.path);
+    }
+
+    let magnite = paths.iter().find(|f| f.path == "minerals.magnite").unwrap();
+    assert_eq!((magnite.min, magnite.max), (0.0, MAX_F32 as f64));
+    assert_eq!(paths.len(), 16 + 8 + 2);
+}
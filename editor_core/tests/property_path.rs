@@ -0,0 +1,125 @@
+mod common;
+
+use gvas::properties::{
+    int_property::{FloatProperty, IntProperty},
+    Property,
+};
+
+use editor_core::{
+    error::PathError,
+    property_path::PropertyPath,
+    registry::{BISMOR, MAGNITE},
+    resources::OwnedResources,
+    save_file::SaveFile,
+};
+
+#[test]
+fn nested_properties_are_read_by_path() {
+    let gvas = common::gvas();
+
+    assert_eq!(
+        gvas.get_property("SchematicSave.OwnedSchematics[1]"),
+        Ok(&common::guid_struct(common::UNKNOWN_SCHEMATIC))
+    );
+    assert_eq!(
+        gvas.get_property(&format!("Resources.OwnedResources{{{MAGNITE}}}")),
+        Ok(&Property::from(FloatProperty::new(
+            OwnedResources::new(&gvas).unwrap().get(MAGNITE).unwrap()
+        )))
+    );
+    assert_eq!(
+        gvas.get_property("CharacterSaves[1].XP"),
+        Ok(&Property::from(IntProperty::new(315000)))
+    );
+    assert_eq!(
+        gvas.get_property("Credits"),
+        Ok(&Property::from(IntProperty::new(1000)))
+    );
+}
+
+#[test]
+fn set_replaces_the_property_at_a_path() {
+    let mut gvas = common::gvas();
+
+    let old = gvas
+        .set_property("CharacterSaves[3].XP", Property::from(IntProperty::new(42)))
+        .unwrap();
+
+    assert_eq!(old, Property::from(IntProperty::new(0)));
+    assert_eq!(SaveFile::from_gvas(&gvas).unwrap().dwarfs.gunner.xp, 42);
+}
+
+#[test]
+fn insert_and_remove_edit_containers() {
+    let mut gvas = common::gvas();
+    let path = format!("Resources.OwnedResources{{{BISMOR}}}");
+
+    let removed = gvas.remove_property(&path).unwrap();
+    assert!(!OwnedResources::new(&gvas).unwrap().contains(BISMOR));
+    assert_eq!(gvas.insert_property(&path, removed.clone()), Ok(None));
+    assert_eq!(gvas.get_property(&path), Ok(&removed));
+
+    let schematic = common::guid_struct(common::FORGED_SCHEMATIC);
+    gvas.insert_property("SchematicSave.OwnedSchematics[0]", schematic.clone())
+        .unwrap();
+    assert_eq!(
+        gvas.get_property("SchematicSave.OwnedSchematics[0]"),
+        Ok(&schematic)
+    );
+    assert_eq!(
+        gvas.remove_property("SchematicSave.OwnedSchematics[0]"),
+        Ok(schematic)
+    );
+
+    assert_eq!(
+        gvas.remove_property("PerkPoints"),
+        Ok(Property::from(IntProperty::new(7)))
+    );
+    assert!(!gvas.properties.contains_key("PerkPoints"));
+}
+
+#[test]
+fn mismatched_segments_report_where_they_failed() {
+    let mut gvas = common::gvas();
+
+    assert_eq!(
+        gvas.get_property("CharacterSaves[9].XP"),
+        Err(PathError::Missing("CharacterSaves[9]".to_string()))
+    );
+    assert_eq!(
+        gvas.get_property("Credits.Amount"),
+        Err(PathError::Mismatch {
+            path: "Credits".to_string(),
+            expected: "StructProperty",
+            found: "IntProperty".to_string(),
+        })
+    );
+    assert_eq!(
+        gvas.get_property("CharacterSaves[0].SavegameID.Value"),
+        Err(PathError::Mismatch {
+            path: "CharacterSaves[0].SavegameID".to_string(),
+            expected: "StructProperty",
+            found: "Guid".to_string(),
+        })
+    );
+    assert_eq!(
+        gvas.insert_property(
+            "SchematicSave.OwnedSchematics[5]",
+            common::guid_struct(MAGNITE)
+        ),
+        Err(PathError::Missing(
+            "SchematicSave.OwnedSchematics[5]".to_string()
+        ))
+    );
+    for path in [
+        "".to_string(),
+        ".Resources".to_string(),
+        "Resources[x]".to_string(),
+        "Resources{not a guid}".to_string(),
+        "A..B".to_string(),
+        "CharacterSaves[0]é}".to_string(),
+        format!("Resources.OwnedResources[0]x{{{MAGNITE}}}}}"),
+    ] {
+        assert_eq!(gvas.get_property(&path), Err(PathError::Syntax(path)));
+    }
+}
@@ -0,0 +1,209 @@
+mod common;
+
+use gvas::{
+    properties::{int_property::IntProperty, str_property::StrProperty, Property},
+    types::Guid,
+    FEngineVersion, GvasFile, GvasHeader,
+};
+
+use editor_core::{
+    registry::{DRILLER, ENGINEER, GUNNER, SCOUT},
+    roundtrip::{verify_roundtrip, RoundTripMismatch},
+    save_file::SaveFile,
+};
+
+fn verify(gvas: &GvasFile) -> Result<(), RoundTripMismatch> {
+    verify_roundtrip(&common::bytes(gvas))
+}
+
+#[test]
+fn full_save() {
+    verify(&common::gvas()).unwrap();
+}
+
+#[test]
+fn version_3_header() {
+    let mut gvas = common::gvas();
+    gvas.header = GvasHeader::Version3 {
+        package_file_version: 522,
+        unknown: 1009,
+        engine_version: FEngineVersion::new(5, 1, 1, 0, "++UE5+Release-5.1".to_string()),
+        custom_version_format: 3,
+        custom_versions: vec![],
+        save_game_class_name: "/Script/FSD.FSDSaveGame".to_string(),
+    };
+
+    verify(&gvas).unwrap();
+}
+
+#[test]
+fn unrelated_properties() {
+    let mut gvas = common::gvas();
+    gvas.properties
+        .insert("Version".to_string(), Property::from(IntProperty::new(33)));
+    gvas.properties.insert(
+        "LastPlayedClass".to_string(),
+        Property::from(StrProperty::new(Some("Scout".to_string()))),
+    );
+    gvas.properties.move_index(gvas.properties.len() - 1, 0);
+
+    verify(&gvas).unwrap();
+}
+
+#[test]
+fn resources_in_any_order() {
+    let mut resources = common::RESOURCES;
+    resources.reverse();
+    resources.swap(3, 9);
+
+    let mut gvas = common::gvas();
+    gvas.properties
+        .insert("Resources".to_string(), common::resources(&resources));
+
+    verify(&gvas).unwrap();
+}
+
+#[test]
+fn missing_resources() {
+    let mut gvas = common::gvas();
+    gvas.properties.insert(
+        "Resources".to_string(),
+        common::resources(&common::RESOURCES[..12]),
+    );
+
+    verify(&gvas).unwrap();
+}
+
+#[test]
+fn resources_with_fractions_and_extremes() {
+    let mut resources = common::RESOURCES;
+    resources[0].1 = 0.5;
+    resources[1].1 = -0.0;
+    resources[2].1 = 268_435_456.0;
+    resources[3].1 = f32::MIN_POSITIVE;
+
+    let mut gvas = common::gvas();
+    gvas.properties
+        .insert("Resources".to_string(), common::resources(&resources));
+
+    verify(&gvas).unwrap();
+}
+
+#[test]
+fn characters_in_any_order() {
+    let mut gvas = common::gvas();
+    gvas.properties.insert(
+        "CharacterSaves".to_string(),
+        common::struct_array(
+            "CharacterSaves",
+            "CharacterSave",
+            vec![
+                common::character(GUNNER, 1, 1),
+                common::character(DRILLER, 2, 2),
+                common::character(SCOUT, 3, 3),
+                common::character(ENGINEER, 4, 4),
+                common::character(Guid([0x11; 16]), 5, 5),
+            ],
+        ),
+    );
+
+    verify(&gvas).unwrap();
+}
+
+#[test]
+fn schematics() {
+    let cases: [(&[Guid], &[Guid]); 4] = [
+        (&[], &[]),
+        (&[common::UNKNOWN_SCHEMATIC], &[common::UNKNOWN_SCHEMATIC]),
+        (
+            &[common::FORGED_SCHEMATIC, common::OWNED_SCHEMATIC],
+            &[common::UNKNOWN_SCHEMATIC],
+        ),
+        (
+            &[common::OWNED_SCHEMATIC, common::OWNED_SCHEMATIC],
+            &[common::FORGED_SCHEMATIC, common::UNKNOWN_SCHEMATIC],
+        ),
+    ];
+
+    for (owned, forged) in cases {
+        let mut gvas = common::gvas();
+        gvas.properties.insert(
+            "SchematicSave".to_string(),
+            common::schematics(owned, forged),
+        );
+
+        verify(&gvas).unwrap();
+    }
+}
+
+#[test]
+fn loadouts_with_characters_and_cosmetics() {
+    let mut gvas = common::gvas();
+    gvas.properties.insert(
+        "ItemUpgradeLoadouts".to_string(),
+        common::struct_array(
+            "ItemUpgradeLoadouts",
+            "ItemUpgradeLoadout",
+            vec![
+                common::character_loadout(SCOUT, &[], vec![]),
+                common::character_loadout(
+                    DRILLER,
+                    &[Guid([0xC5; 16])],
+                    vec![(
+                        common::WEAPON,
+                        common::upgrade_selection(&[0, 1], Guid::default()),
+                    )],
+                ),
+                common::loadout(vec![]),
+            ],
+        ),
+    );
+
+    verify(&gvas).unwrap();
+}
+
+#[test]
+fn duplicate_characters() {
+    let mut gvas = common::gvas();
+    gvas.properties.insert(
+        "CharacterSaves".to_string(),
+        common::struct_array(
+            "CharacterSaves",
+            "CharacterSave",
+            vec![
+                common::character(ENGINEER, 1, 0),
+                common::character(DRILLER, 0, 0),
+                common::character(GUNNER, 0, 0),
+                common::character(SCOUT, 0, 0),
+                common::character(ENGINEER, 2, 0),
+            ],
+        ),
+    );
+
+    verify(&gvas).unwrap();
+    assert_eq!(SaveFile::from_gvas(&gvas).unwrap().dwarfs.engineer.xp, 1);
+}
+
+#[test]
+fn reports_where_the_output_differs() {
+    let mut bytes = common::bytes(&common::gvas());
+    let len = bytes.len();
+    bytes.extend([0; 4]);
+
+    let err = verify_roundtrip(&bytes).unwrap_err();
+
+    match err {
+        RoundTripMismatch::Mismatch { path, offset } => {
+            assert_eq!(path, "None");
+            assert_eq!(offset, len);
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+#[test]
+fn reports_invalid_input() {
+    let err = verify_roundtrip(b"not a save file").unwrap_err();
+
+    assert!(matches!(err, RoundTripMismatch::Load(_)));
+}
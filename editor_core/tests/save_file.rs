@@ -0,0 +1,851 @@
+mod common;
+
+use gvas::{
+    properties::{
+        int_property::{FloatProperty, IntProperty},
+        Property,
+    },
+    types::Guid,
+    GvasFile,
+};
+
+use editor_core::{
+    error::{Error, ParsingError, PathError},
+    property_path::PropertyPath,
+    read_gvas_bytes,
+    registry::{Dwarf, Resource, BISMOR, CROPPA, ENGINEER, GUNNER, MAGNITE, PHAZYONITE, SCOUT},
+    resources::OwnedResources,
+    save_file::{events::EventRewards, loadouts::Loadout, upgrades::UpgradeSelection, SaveFile},
+};
+
+use common::Mutators;
+
+#[test]
+fn unchanged_save_reads_back_equal() {
+    let gvas = common::gvas();
+    let save = common::load(&gvas);
+
+    assert_eq!(common::reload(&gvas, &save), save);
+}
+
+#[test]
+fn edited_resources_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    save.minerals.magnite = 4242.0;
+    save.brewing.barley_bulb = 77.0;
+    save.miscellaneous.phazyonite = 12.0;
+    save.miscellaneous.credits = 123_456;
+    save.miscellaneous.perk_points = 99;
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.minerals.magnite, 4242.0);
+    assert_eq!(reread.brewing.barley_bulb, 77.0);
+    assert_eq!(reread.miscellaneous.phazyonite, 12.0);
+    assert_eq!(reread.miscellaneous.credits, 123_456);
+    assert_eq!(reread.miscellaneous.perk_points, 99);
+    assert_eq!(reread, save);
+}
+
+#[test]
+#[cfg(not(feature = "wasm"))]
+fn write_to_creates_a_readable_file() {
+    let mut gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let path = std::env::temp_dir().join(format!("editor_core_{}.sav", std::process::id()));
+
+    save.minerals.jadiz = 1.0;
+    save.write_to(&mut gvas, &path).unwrap();
+
+    let reread = SaveFile::from_path(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(reread.unwrap(), save);
+}
+
+#[test]
+fn edited_dwarfs_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    save.dwarfs.gunner.xp = 52_000;
+    save.dwarfs.engineer.times_retired = 5;
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.dwarfs.gunner.xp, 52_000);
+    assert_eq!(reread.dwarfs.engineer.times_retired, 5);
+    assert_eq!(reread.dwarfs.engineer.promotion, "Silver 2");
+}
+
+#[test]
+fn missing_dwarf_entry_fails_to_save() {
+    let mut gvas = common::gvas();
+    let save = SaveFile::from_gvas(&gvas).unwrap();
+    gvas.properties.insert(
+        "CharacterSaves".to_string(),
+        common::struct_array(
+            "CharacterSaves",
+            "CharacterSave",
+            vec![common::character(ENGINEER, 0, 0)],
+        ),
+    );
+
+    let err = save.save(&mut gvas).unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Parsing(ParsingError::MissingEntry { path, section })
+            if path == "CharacterSaves.Driller" && section.as_deref() == Some("dwarfs")
+    ));
+}
+
+#[test]
+fn missing_resources_read_as_zero_and_are_added_on_save() {
+    let mut gvas = common::gvas();
+    gvas.properties.insert(
+        "Resources".to_string(),
+        common::resources(&common::RESOURCES[..13]),
+    );
+    let mut save = common::load(&gvas);
+    assert_eq!(save.miscellaneous.phazyonite, 0.0);
+
+    save.miscellaneous.phazyonite = 25.0;
+    let out = common::resave(&gvas, &save);
+
+    let reread = read_gvas_bytes(&out).unwrap();
+    let resources = OwnedResources::new(&reread).unwrap();
+    assert_eq!(resources.len(), 14);
+    assert_eq!(resources.get(PHAZYONITE), Some(25.0));
+    assert_eq!(
+        SaveFile::from_bytes(&out).unwrap().miscellaneous.phazyonite,
+        25.0
+    );
+}
+
+#[test]
+fn owned_resources_keep_their_order_when_edited() {
+    let mut gvas = common::gvas();
+    let mut resources = OwnedResources::new_mut(&mut gvas).unwrap();
+
+    *resources.get_mut(CROPPA).unwrap() = 1.0;
+    assert_eq!(resources.remove(BISMOR), Some(200.0));
+    assert_eq!(resources.insert(BISMOR, 2.0), None);
+    assert_eq!(resources.insert(MAGNITE, 3.0), Some(100.0));
+
+    let resources = OwnedResources::new(&gvas).unwrap();
+    let guids = resources.iter().map(|(g, _)| g).collect::<Vec<_>>();
+    let mut expected = common::RESOURCES
+        .iter()
+        .map(|(g, _)| *g)
+        .filter(|g| *g != BISMOR)
+        .collect::<Vec<_>>();
+    expected.push(BISMOR);
+
+    assert_eq!(guids, expected);
+    assert_eq!(resources.get(CROPPA), Some(1.0));
+    assert_eq!(resources.get(MAGNITE), Some(3.0));
+    assert!(!resources.contains(Guid::from(0x5eed)));
+}
+
+#[test]
+fn resources_are_reachable_through_the_resource_enum() {
+    let mut save = SaveFile::from_gvas(&common::gvas()).unwrap();
+
+    for (guid, amount) in common::RESOURCES {
+        let resource = Resource::from_guid(guid).unwrap();
+        assert_eq!(resource.guid(), Some(guid));
+        assert_eq!(save.resource(resource), amount as f64);
+    }
+    assert_eq!(save.resources().count(), Resource::ALL.len());
+
+    save.set_resource(Resource::Phazyonite, 12.0).unwrap();
+    let credits = save.miscellaneous.credits;
+
+    assert_eq!(save.miscellaneous.phazyonite, 12.0);
+    assert!(matches!(
+        save.set_resource(Resource::Credits, -5.0),
+        Err(Error::Parsing(ParsingError::InvalidValue { .. }))
+    ));
+    assert!(save
+        .set_resource(Resource::PerkPoints, Resource::PerkPoints.max() + 1.0)
+        .is_err());
+    assert!(matches!(
+        save.set_resource(Resource::Credits, 1.5),
+        Err(Error::Parsing(ParsingError::InvalidValue { .. }))
+    ));
+    save.set_resource(Resource::Magnite, 1.5).unwrap();
+    assert_eq!(save.minerals.magnite, 1.5);
+    assert_eq!(save.miscellaneous.credits, credits);
+    assert_eq!(save.resource(Resource::PerkPoints), 7.0);
+}
+
+#[test]
+fn characters_are_read_by_field_name() {
+    let mut gvas = common::gvas();
+    let unknown = Guid::from(0x5eed);
+    gvas.properties.insert(
+        "CharacterSaves".to_string(),
+        common::struct_array(
+            "CharacterSaves",
+            "CharacterSave",
+            vec![
+                common::custom_struct(
+                    "CharacterSave",
+                    vec![
+                        ("TimesRetired", Property::from(IntProperty::new(2))),
+                        ("XP", Property::from(IntProperty::new(4_000))),
+                        ("SavegameID", common::guid_struct(ENGINEER)),
+                    ],
+                ),
+                common::character(unknown, 0, 0),
+            ],
+        ),
+    );
+
+    let save = SaveFile::from_gvas(&gvas).unwrap();
+
+    assert_eq!(save.dwarfs.engineer.xp, 4_000);
+    assert_eq!(save.dwarfs.engineer.times_retired, 2);
+    assert_eq!(save.dwarfs.unknown, vec![unknown]);
+}
+
+#[test]
+fn truncated_character_fails_to_load() {
+    let mut gvas = common::gvas();
+    gvas.properties.insert(
+        "CharacterSaves".to_string(),
+        common::struct_array(
+            "CharacterSaves",
+            "CharacterSave",
+            vec![common::custom_struct(
+                "CharacterSave",
+                vec![("SavegameID", common::guid_struct(ENGINEER))],
+            )],
+        ),
+    );
+
+    let err = SaveFile::from_gvas(&gvas).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "dwarfs: Entry CharacterSaves[0].XP is missing"
+    );
+}
+
+#[test]
+fn wrong_property_type_reports_path_and_types() {
+    let mut gvas = common::gvas();
+    gvas.properties.insert(
+        "SeasonSave".to_string(),
+        common::seasons(vec![(
+            common::SEASON,
+            common::custom_struct(
+                "SeasonSaveEntry",
+                vec![("XP", Property::from(FloatProperty::new(1.0)))],
+            ),
+        )]),
+    );
+
+    let err = SaveFile::from_gvas(&gvas).unwrap_err();
+
+    match err {
+        Error::Parsing(ParsingError::FailedCastError {
+            path,
+            expected,
+            actual,
+            section,
+        }) => {
+            assert_eq!(path, format!("SeasonSave.Seasons{{{}}}.XP", common::SEASON));
+            assert_eq!(expected, "IntProperty");
+            assert_eq!(actual, "FloatProperty");
+            assert_eq!(section.as_deref(), Some("seasons"));
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+fn schematic_guids(gvas: &GvasFile, array: &str) -> Vec<Guid> {
+    let schematic_save = gvas.properties["SchematicSave"]
+        .get_struct()
+        .and_then(|s| s.value.get_custom_struct())
+        .unwrap()
+        .1;
+
+    schematic_save
+        .iter()
+        .find(|p| p.0 == array)
+        .and_then(|p| p.1.get_array())
+        .unwrap()
+        .properties
+        .iter()
+        .map(|p| *p.get_struct().unwrap().value.get_guid().unwrap())
+        .collect()
+}
+
+#[test]
+fn edited_forge_is_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    assert!(save.forge.forge(common::OWNED_SCHEMATIC));
+    assert!(save.forge.unforge(common::FORGED_SCHEMATIC));
+    assert!(save.forge.revoke(common::FORGED_SCHEMATIC));
+    assert!(save.forge.grant(common::FORGED_SCHEMATIC));
+    assert!(!save.forge.grant(common::UNKNOWN_SCHEMATIC));
+    let out = common::resave(&gvas, &save);
+
+    let reread = read_gvas_bytes(&out).unwrap();
+    assert_eq!(
+        schematic_guids(&reread, "OwnedSchematics"),
+        vec![common::UNKNOWN_SCHEMATIC, common::FORGED_SCHEMATIC]
+    );
+    assert_eq!(
+        schematic_guids(&reread, "ForgedSchematics"),
+        vec![common::OWNED_SCHEMATIC]
+    );
+    assert_eq!(SaveFile::from_bytes(&out).unwrap(), save);
+}
+
+#[test]
+fn edited_seasons_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let challenge = Guid([0xC1; 16]);
+
+    let season = save.seasons.get_mut(common::SEASON).unwrap();
+    assert_eq!(season.guid(), common::SEASON);
+    assert_eq!(season.completed_challenges, vec![common::CHALLENGE]);
+    season.xp = 90_000;
+    season.scrip = 3;
+    season.reset_challenge(common::CHALLENGE);
+    season.complete_challenge(challenge);
+
+    let reread = common::reload(&gvas, &save);
+    let season = reread.seasons.get(common::SEASON).unwrap();
+    assert_eq!(season.xp, 90_000);
+    assert_eq!(season.scrip, 3);
+    assert_eq!(season.completed_challenges, vec![challenge]);
+}
+
+#[test]
+fn missing_season_save_is_empty() {
+    let mut gvas = common::gvas();
+    gvas.properties.remove("SeasonSave");
+
+    let save = SaveFile::from_gvas(&gvas).unwrap();
+    save.save(&mut gvas).unwrap();
+
+    assert!(save.seasons.is_empty());
+    assert!(!gvas.properties.contains_key("SeasonSave"));
+}
+
+#[test]
+fn edited_upgrades_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    let selection = save.upgrades.get_mut(common::WEAPON).unwrap();
+    assert_eq!(selection.tiers, vec![0, 1, -1, 2, -1]);
+    assert_eq!(selection.overclock, None);
+    selection.set_tier(2, Some(1));
+    selection.set_tier(0, None);
+    selection.overclock = Some(common::OWNED_SCHEMATIC);
+
+    let reread = common::reload(&gvas, &save);
+    let selection = reread.upgrades.get(common::WEAPON).unwrap();
+    assert_eq!(selection.tiers, vec![-1, 1, 1, 2, -1]);
+    assert_eq!(selection.overclock, Some(common::OWNED_SCHEMATIC));
+}
+
+#[test]
+fn added_and_removed_upgrades_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    let added = UpgradeSelection {
+        item: common::PISTOL,
+        tiers: vec![1, -1, 0],
+        overclock: Some(common::FORGED_SCHEMATIC),
+    };
+    save.upgrades.selections.push(added.clone());
+    save.upgrades
+        .selections
+        .retain(|s| s.item != common::WEAPON);
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.upgrades.selections, vec![added]);
+
+    save.upgrades.selections.clear();
+    assert!(common::reload(&gvas, &save).upgrades.selections.is_empty());
+}
+
+#[test]
+fn copied_loadouts_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    assert_eq!(save.loadouts.slots.len(), 2);
+    assert!(save.loadouts.copy(0, 1));
+    assert!(!save.loadouts.copy(0, 2));
+    let slot = save.loadouts.slots[0].get_mut(common::WEAPON).unwrap();
+    slot.set_tier(1, Some(2));
+
+    let reread = common::reload(&gvas, &save);
+    let [first, second] = &reread.loadouts.slots[..] else {
+        panic!("expected two loadouts");
+    };
+    assert_eq!(first.get(common::WEAPON).unwrap().tiers, vec![0, 2, 0]);
+    assert_eq!(second.get(common::WEAPON).unwrap().tiers, vec![0, 0, 0]);
+    assert_eq!(
+        second.get(common::PISTOL).unwrap().overclock,
+        Some(common::OWNED_SCHEMATIC)
+    );
+}
+
+#[test]
+fn loadouts_can_be_imported_from_another_save() {
+    let gvas = common::gvas();
+    let source = common::load(&gvas);
+    let mut save = source.clone();
+
+    assert!(save.loadouts.import(0, &source.loadouts.slots[1]));
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.loadouts.slots[0], source.loadouts.slots[1]);
+}
+
+#[test]
+fn copied_loadouts_keep_their_dwarf() {
+    let mut gvas = common::gvas();
+    let helmet = Guid([0xC5; 16]);
+    gvas.properties.insert(
+        "ItemUpgradeLoadouts".to_string(),
+        common::struct_array(
+            "ItemUpgradeLoadouts",
+            "ItemUpgradeLoadout",
+            vec![
+                common::character_loadout(SCOUT, &[helmet], vec![]),
+                common::character_loadout(Dwarf::Driller.guid(), &[], vec![]),
+                common::character_loadout(
+                    Dwarf::Driller.guid(),
+                    &[],
+                    vec![(
+                        common::WEAPON,
+                        common::upgrade_selection(&[1], Guid::default()),
+                    )],
+                ),
+                common::loadout(vec![]),
+            ],
+        ),
+    );
+    let mut save = common::load(&gvas);
+    let before = save.loadouts.clone();
+
+    assert!(!save.loadouts.copy(0, 1));
+    assert!(!save.loadouts.import(1, &before.slots[0]));
+    assert_eq!(save.loadouts, before);
+
+    assert!(save.loadouts.copy(2, 1));
+    assert!(save.loadouts.copy(0, 3));
+
+    let reread = common::reload(&gvas, &save);
+    let driller = &reread.loadouts.slots[1];
+    assert_eq!(driller.dwarf(), Some(Dwarf::Driller));
+    assert_eq!(driller.items, before.slots[2].items);
+    let unnamed = &reread.loadouts.slots[3];
+    assert_eq!(
+        (unnamed.character, &unnamed.cosmetics),
+        (None, &vec![helmet])
+    );
+}
+
+#[test]
+fn loadout_dwarfs_and_cosmetics_are_written() {
+    let mut gvas = common::gvas();
+    let helmet = Guid([0xC5; 16]);
+    let beard = Guid([0xC6; 16]);
+    gvas.properties.insert(
+        "ItemUpgradeLoadouts".to_string(),
+        common::struct_array(
+            "ItemUpgradeLoadouts",
+            "ItemUpgradeLoadout",
+            vec![
+                common::character_loadout(GUNNER, &[helmet], vec![]),
+                common::loadout(vec![]),
+            ],
+        ),
+    );
+    let mut save = common::load(&gvas);
+
+    let [gunner, other] = &save.loadouts.slots[..] else {
+        panic!("expected two loadouts");
+    };
+    assert_eq!(gunner.dwarf(), Some(Dwarf::Gunner));
+    assert_eq!(gunner.cosmetics, vec![helmet]);
+    assert_eq!((other.character, other.cosmetics.len()), (None, 0));
+
+    save.loadouts.slots[0].cosmetics = vec![beard];
+    save.loadouts.slots[1].character = Some(Dwarf::Scout.guid());
+    save.loadouts.slots[1].cosmetics = vec![helmet, beard];
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.loadouts, save.loadouts);
+    assert_eq!(
+        reread
+            .loadouts
+            .of_dwarf(Dwarf::Scout)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>(),
+        vec![1]
+    );
+}
+
+#[test]
+fn added_and_removed_loadout_slots_are_written() {
+    let mut gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let pistol = save.loadouts.slots[0].get(common::PISTOL).unwrap().clone();
+
+    save.loadouts.slots.remove(0);
+    save.loadouts.slots.push(Loadout {
+        character: Some(Dwarf::Driller.guid()),
+        items: vec![pistol],
+        cosmetics: vec![Guid([0xC5; 16])],
+    });
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.loadouts, save.loadouts);
+
+    gvas.properties.remove("ItemUpgradeLoadouts");
+    let mut save = common::load(&gvas);
+    assert!(save.loadouts.slots.is_empty());
+    save.loadouts.slots.push(Loadout::default());
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.loadouts.slots, vec![Loadout::default()]);
+}
+
+#[test]
+fn enemy_kills_are_read() {
+    let save = SaveFile::from_gvas(&common::gvas()).unwrap();
+
+    assert_eq!(save.enemy_stats.enemies.len(), 2);
+    assert_eq!(
+        save.enemy_stats.get(common::GLYPHID_GRUNT).unwrap().kills,
+        1200
+    );
+    assert_eq!(
+        save.enemy_stats.get(common::MACTERA_SPAWN).unwrap().kills,
+        34
+    );
+    assert_eq!(save.enemy_stats.total(), 1234);
+}
+
+#[test]
+fn edited_skins_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let skin = Guid([0xF3; 16]);
+    let item = Guid([0x3C; 16]);
+
+    assert!(save.skins.lock(common::WEAPON, common::FRAMEWORK));
+    assert!(!save.skins.lock(common::WEAPON, common::FRAMEWORK));
+    assert!(save.skins.unlock(common::WEAPON, skin));
+    assert!(save.skins.unlock(item, skin));
+
+    let reread = common::reload(&gvas, &save);
+    let items = reread
+        .skins
+        .items
+        .iter()
+        .map(|i| (i.item, i.skins.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        items,
+        vec![
+            (common::WEAPON, vec![common::PAINT_JOB, skin]),
+            (common::PISTOL, vec![common::PAINT_JOB]),
+            (item, vec![skin]),
+        ]
+    );
+}
+
+#[test]
+fn items_without_skins_lose_their_entry() {
+    let mut gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    assert!(save.skins.lock(common::PISTOL, common::PAINT_JOB));
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.skins.get(common::PISTOL), None);
+    assert_eq!(reread.skins.items.len(), 1);
+
+    gvas.properties.remove("UnlockedItemSkins");
+    let mut save = common::load(&gvas);
+    assert!(save.skins.unlock(common::PISTOL, common::FRAMEWORK));
+    let reread = common::reload(&gvas, &save);
+    assert!(reread.skins.is_unlocked(common::PISTOL, common::FRAMEWORK));
+}
+
+#[test]
+fn edited_drinks_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    assert!(save.drinks.is_unlocked(common::LEAF_LOVERS_SPECIAL));
+    assert!(save.drinks.unlock(common::BLACKOUT_STOUT));
+    assert!(!save.drinks.unlock(common::BLACKOUT_STOUT));
+    assert!(save.drinks.lock(common::LEAF_LOVERS_SPECIAL));
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.drinks.unlocked, vec![common::BLACKOUT_STOUT]);
+}
+
+#[test]
+fn drinks_are_added_to_a_save_without_any() {
+    let mut gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    assert!(save.drinks.lock(common::LEAF_LOVERS_SPECIAL));
+    let out = common::resave(&gvas, &save);
+    assert!(SaveFile::from_bytes(&out)
+        .unwrap()
+        .drinks
+        .unlocked
+        .is_empty());
+
+    gvas = read_gvas_bytes(&out).unwrap();
+    assert!(save.drinks.unlock(common::BLACKOUT_STOUT));
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.drinks.unlocked, vec![common::BLACKOUT_STOUT]);
+}
+
+#[test]
+fn edited_pickaxe_parts_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let handle = Guid([0xB3; 16]);
+
+    assert!(save.pickaxe_parts.lock(common::PICKAXE_BLADE));
+    assert!(save.pickaxe_parts.unlock(handle));
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(
+        reread.pickaxe_parts.unlocked,
+        vec![common::PICKAXE_POMMEL, handle]
+    );
+}
+
+#[test]
+fn pickaxe_parts_are_unlocked_in_bulk() {
+    let mut gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let handle = Guid([0xB3; 16]);
+
+    let parts = [common::PICKAXE_BLADE, common::PICKAXE_POMMEL];
+    assert_eq!(
+        parts
+            .iter()
+            .filter(|p| save.pickaxe_parts.lock(**p))
+            .count(),
+        2
+    );
+    let out = common::resave(&gvas, &save);
+    assert!(SaveFile::from_bytes(&out)
+        .unwrap()
+        .pickaxe_parts
+        .unlocked
+        .is_empty());
+
+    gvas = read_gvas_bytes(&out).unwrap();
+    assert!(!gvas.properties.contains_key("UnlockedPickaxeParts"));
+    assert_eq!(
+        save.pickaxe_parts
+            .unlock_all([handle, common::PICKAXE_BLADE]),
+        2
+    );
+    assert_eq!(save.pickaxe_parts.unlock_all([handle]), 0);
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(
+        reread.pickaxe_parts.unlocked,
+        vec![handle, common::PICKAXE_BLADE]
+    );
+}
+
+#[test]
+fn reset_miners_manual_is_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    assert!(save.miners_manual.is_discovered(common::MANUAL_NITRA));
+    save.miners_manual.reset();
+    let out = common::resave(&gvas, &save);
+
+    let mut reread = SaveFile::from_bytes(&out).unwrap();
+    assert!(reread.miners_manual.known_objects.is_empty());
+
+    let gvas = read_gvas_bytes(&out).unwrap();
+    let objects = [common::MANUAL_NITRA, common::MANUAL_GLYPHID];
+    assert_eq!(reread.miners_manual.discover_all(objects), 2);
+    assert_eq!(reread.miners_manual.discover_all(objects), 0);
+    let reread = common::reload(&gvas, &reread);
+    assert_eq!(reread.miners_manual.known_objects, objects);
+}
+
+#[test]
+fn edited_event_rewards_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let event = Guid([0xE3; 16]);
+
+    assert!(save.event_rewards.is_event_seen(common::EVENT));
+    save.event_rewards.set_event_seen(common::EVENT, false);
+    save.event_rewards.set_event_seen(event, true);
+    save.event_rewards.set_popup_seen(common::POPUP, false);
+    save.event_rewards.set_popup_seen(event, true);
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.event_rewards.events_seen, vec![event]);
+    assert_eq!(reread.event_rewards.popups_seen, vec![event]);
+}
+
+#[test]
+fn last_seen_event_is_removed_and_added_back() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    save.event_rewards.set_event_seen(common::EVENT, false);
+    save.event_rewards.set_popup_seen(common::POPUP, false);
+    let out = common::resave(&gvas, &save);
+
+    let gvas = read_gvas_bytes(&out).unwrap();
+    assert_eq!(
+        gvas.get_property("FSDEventRewardsSave.EventsSeen"),
+        Err(PathError::Missing(
+            "FSDEventRewardsSave.EventsSeen".to_string()
+        ))
+    );
+    let mut save = SaveFile::from_bytes(&out).unwrap();
+    assert_eq!(save.event_rewards, EventRewards::default());
+
+    save.event_rewards.set_event_seen(common::EVENT, true);
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.event_rewards.events_seen, vec![common::EVENT]);
+    assert!(reread.event_rewards.popups_seen.is_empty());
+}
+
+#[test]
+fn edited_dlc_announcements_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let dlc = Guid([0xD8; 16]);
+
+    assert!(save.dlc.is_announced(common::DLC));
+    save.dlc.clear();
+    assert!(save.dlc.announce(dlc));
+    assert!(!save.dlc.remove(common::DLC));
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.dlc.announced, vec![dlc]);
+}
+
+#[test]
+fn cleared_dlc_announcements_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+
+    save.dlc.clear();
+
+    let reread = common::reload(&gvas, &save);
+    assert!(reread.dlc.announced.is_empty());
+}
+
+#[test]
+fn toggled_mission_parameters_are_written() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let parameter = Guid([0x4E; 16]);
+
+    assert!(save
+        .mission_parameters
+        .is_unlocked(common::MISSION_PARAMETER));
+    save.mission_parameters.toggle(parameter);
+    save.mission_parameters.toggle(common::MISSION_PARAMETER);
+
+    let reread = common::reload(&gvas, &save);
+    assert_eq!(reread.mission_parameters.unlocked, vec![parameter]);
+}
+
+#[test]
+fn mission_parameters_are_not_unlocked_into_an_empty_map() {
+    let gvas = common::gvas();
+    let mut save = common::load(&gvas);
+    let parameter = Guid([0x4E; 16]);
+
+    save.mission_parameters.toggle(common::MISSION_PARAMETER);
+    let out = common::resave(&gvas, &save);
+    let mut gvas = read_gvas_bytes(&out).unwrap();
+    assert!(common::load(&gvas).mission_parameters.unlocked.is_empty());
+
+    // Nothing to copy a new entry from.
+    save.mission_parameters.set_unlocked(parameter, true);
+    assert!(matches!(
+        save.save(&mut gvas),
+        Err(Error::Parsing(ParsingError::InvalidValue { .. }))
+    ));
+
+    gvas.properties.remove("UnLockedMissionParameters");
+    assert!(matches!(
+        save.save(&mut gvas),
+        Err(Error::Parsing(ParsingError::MissingEntry { .. }))
+    ));
+}
+
+#[test]
+fn invalid_sections_stop_the_save_before_writing() {
+    let original = common::gvas();
+    let mut gvas = original.clone();
+    let mut save = SaveFile::from_gvas(&gvas).unwrap();
+
+    save.miscellaneous.credits = 5;
+    save.dwarfs.scout.xp = -1;
+
+    match save.save(&mut gvas) {
+        Err(Error::Parsing(e @ ParsingError::InvalidValue { .. })) => {
+            assert_eq!(e.section(), Some("dwarfs"));
+            assert_eq!(e.path(), format!("CharacterSaves{{{}}}.XP", SCOUT));
+        }
+        other => panic!("unexpected result {other:?}"),
+    }
+    assert_eq!(gvas, original);
+}
+
+#[test]
+fn registered_sections_are_read_and_written() {
+    let mut gvas = common::gvas();
+    let mut save = SaveFile::from_gvas(&gvas).unwrap();
+
+    match save.register::<Mutators>(&gvas) {
+        Err(Error::Parsing(e)) => {
+            assert_eq!(e.to_string(), "mutators: Entry MutatorLevel is missing")
+        }
+        other => panic!("unexpected result {other:?}"),
+    }
+
+    gvas.properties.insert(
+        "MutatorLevel".to_string(),
+        Property::from(IntProperty::new(3)),
+    );
+    save.register::<Mutators>(&gvas).unwrap();
+    assert_eq!(save.section::<Mutators>(), Some(&Mutators { level: 3 }));
+
+    save.section_mut::<Mutators>().unwrap().level = -2;
+    assert!(save.save(&mut gvas).is_err());
+
+    save.section_mut::<Mutators>().unwrap().level = 8;
+    save.save(&mut gvas).unwrap();
+    assert_eq!(
+        gvas.properties["MutatorLevel"],
+        Property::from(IntProperty::new(8))
+    );
+}
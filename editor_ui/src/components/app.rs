@@ -1,11 +1,11 @@
 use crate::components::{file_upload::FileUpload, save_display::SaveDisplay};
-use editor_core::save_file::SaveFile;
+use editor_core::document::SaveDocument;
 use leptos::*;
 
 #[component]
 pub(crate) fn App(cx: Scope) -> impl IntoView {
     let (_, set_file_name) = create_signal(cx, None::<String>);
-    let save_file = create_rw_signal(cx, None::<SaveFile>);
+    let save_file = create_rw_signal(cx, None::<SaveDocument>);
 
     provide_context(cx, save_file);
 
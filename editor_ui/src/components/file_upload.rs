@@ -1,4 +1,4 @@
-use editor_core::save_file::SaveFile;
+use editor_core::document::SaveDocument;
 use leptos::{html::Input, *};
 use web_sys::Event;
 
@@ -6,7 +6,7 @@ use web_sys::Event;
 pub(crate) fn FileUpload(
     cx: Scope,
     set_file_name: WriteSignal<Option<String>>,
-    set_save_file: WriteSignal<Option<SaveFile>>,
+    set_save_file: WriteSignal<Option<SaveDocument>>,
 ) -> impl IntoView {
     let file_input = create_node_ref::<Input>(cx);
     let on_file_change = move |_ev: Event| {
@@ -21,9 +21,12 @@ pub(crate) fn FileUpload(
                     .await
                     .unwrap();
                 let byte_arr = js_sys::Uint8Array::new(&bytes);
-                let buffer = &byte_arr.to_vec()[..];
+                let buffer = byte_arr.to_vec();
 
-                let save_file = SaveFile::from_bytes(buffer).expect("valid save file");
+                let save_file = SaveDocument::from_bytes_lenient(buffer).expect("valid save file");
+                for diagnostic in save_file.diagnostics() {
+                    warn!("{diagnostic}");
+                }
                 set_save_file.update(|n| *n = Some(save_file));
             })
         }
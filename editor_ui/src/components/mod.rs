@@ -1,4 +1,4 @@
 pub(crate) mod app;
 pub(crate) mod file_upload;
 pub(crate) mod save_display;
-pub(crate) mod minerals;
\ No newline at end of file
+pub(crate) mod resources;
\ No newline at end of file
@@ -0,0 +1,50 @@
+use editor_core::{document::SaveDocument, registry::Resource};
+use leptos::*;
+
+#[component]
+fn ResourceInput(cx: Scope, resource: Resource) -> impl IntoView {
+    let save_file = use_context::<RwSignal<Option<SaveDocument>>>(cx).expect("save file");
+
+    let value = move || {
+        save_file.with(|s| {
+            s.as_ref()
+                .map(|s| format!("{:.0}", s.save().resource(resource)))
+        })
+    };
+
+    let on_input = move |ev| {
+        let val = event_target_value(&ev);
+        let num = val.parse::<f64>().unwrap_or_default();
+        save_file.update(|s| {
+            if let Some(s) = s {
+                // Out of range input is dropped, the field shows the kept amount.
+                let _ = s.save_mut().set_resource(resource, num);
+            }
+        });
+    };
+
+    view! { cx,
+        <div>
+        <p>{value}</p>
+            <label>{resource.name()}</label>
+            <input
+                type="number"
+                min=0
+                max=resource.max()
+                prop:value=value
+                on:change=on_input
+            />
+        </div>
+    }
+}
+
+#[component]
+pub(crate) fn Resources(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <input type="number" min=0 max=100/>
+        {Resource::ALL
+            .into_iter()
+            .map(|resource| view! { cx, <ResourceInput resource/> })
+            .collect::<Vec<_>>()}
+    }
+}